@@ -0,0 +1,344 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_errors;
+extern crate rustc_hir;
+extern crate rustc_middle;
+extern crate rustc_span;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_parent_expr;
+use clippy_utils::source::{snippet_indent, snippet_with_applicability};
+use leptos_lints_utils::context::is_context_read;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::paths;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{Visitor, walk_expr};
+use rustc_hir::{
+    BinOpKind, Closure, ClosureKind, CoroutineDesugaring, CoroutineKind, Expr, ExprKind, HirId,
+    LetStmt, MatchSource, Node,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, TypeckResults};
+use rustc_span::Span;
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for contexts read with `use_context`, `expect_context` or the hooks of
+    /// other crates, like `leptos_router::hooks::use_navigate`, that can only be
+    /// reached after a suspension point of an async block or `async fn`. This
+    /// includes the futures passed to `spawn_local`, `Action::new`, `Resource::new`
+    /// or `Suspend::new`.
+    ///
+    /// Futures passed directly to `leptos::task::spawn_local_scoped` are not
+    /// checked, as the owner is restored every time they are polled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The code before the first `.await` runs while the reactive owner that
+    /// created the future is current, but the code after it runs whenever the
+    /// future is woken up, when another owner or none may be current. Contexts read
+    /// there are not found, and `expect_context` panics at run-time.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// let save = Action::new(move |data: &Data| async move {
+    ///     let response = send(data).await;
+    ///     let toaster = expect_context::<Toaster>();
+    ///     toaster.show(response);
+    /// });
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// let save = Action::new(move |data: &Data| async move {
+    ///     let toaster = expect_context::<Toaster>();
+    ///     let response = send(data).await;
+    ///     toaster.show(response);
+    /// });
+    /// ```
+    ///
+    /// When the context is read by a `let` statement of the block that contains the
+    /// first `.await`, the lint suggests moving the statement before it.
+    pub LEPTOS_CONTEXT_AFTER_AWAIT,
+    Warn,
+    "Checks for contexts read after a suspension point in async code"
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosContextAfterAwait {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Closure(&Closure {
+            kind: ClosureKind::Coroutine(CoroutineKind::Desugared(CoroutineDesugaring::Async, _)),
+            body,
+            ..
+        }) = expr.kind
+        else {
+            return;
+        };
+
+        if is_scoped_spawn_future(cx, expr) || !should_lint(cx.tcx, Side::Both) {
+            return;
+        }
+
+        let mut flow = AwaitFlow {
+            cx,
+            typeck_results: cx.tcx.typeck_body(body),
+            state: State::Unsuspended,
+            breaks: Vec::new(),
+            reads: Vec::new(),
+        };
+        flow.visit_expr(cx.tcx.hir_body(body).value);
+
+        for (read, await_span) in flow.reads {
+            span_lint_and_then(
+                cx,
+                LEPTOS_CONTEXT_AFTER_AWAIT,
+                read.span,
+                "context read after a suspension point",
+                |diag| {
+                    diag.span_note(
+                        await_span,
+                        "the reactive owner may no longer be current after this `.await`",
+                    );
+                    if let Some(edits) = hoist_read(cx, read, await_span) {
+                        diag.multipart_suggestion(
+                            "read the context before the first `.await`",
+                            edits,
+                            Applicability::MaybeIncorrect,
+                        );
+                    } else {
+                        diag.help("read the context before the first `.await`");
+                    }
+                    diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_context_after_await#readme");
+                },
+            );
+        }
+    }
+}
+
+fn is_scoped_spawn_future(cx: &LateContext<'_>, future: &Expr<'_>) -> bool {
+    if let Some(parent) = get_parent_expr(cx, future)
+        && let ExprKind::Call(callee, [_]) = parent.kind
+        && let ExprKind::Path(ref qpath) = callee.kind
+        && let Some(def_id) = cx.qpath_res(qpath, callee.hir_id).opt_def_id()
+    {
+        paths::SPAWN_LOCAL_SCOPED.matches(cx, def_id)
+    } else {
+        false
+    }
+}
+
+/// Edits moving a `let` statement that reads a context before the statement of
+/// the same block that contains the first `.await`.
+fn hoist_read(
+    cx: &LateContext<'_>,
+    read: &Expr<'_>,
+    await_span: Span,
+) -> Option<Vec<(Span, String)>> {
+    let has_args = match read.kind {
+        ExprKind::Call(_, args) | ExprKind::MethodCall(_, _, args, _) => !args.is_empty(),
+        _ => true,
+    };
+    let Node::LetStmt(&LetStmt {
+        els: None, hir_id, ..
+    }) = cx.tcx.parent_hir_node(read.hir_id)
+    else {
+        return None;
+    };
+    let Node::Stmt(stmt) = cx.tcx.parent_hir_node(hir_id) else {
+        return None;
+    };
+    let Node::Block(block) = cx.tcx.parent_hir_node(stmt.hir_id) else {
+        return None;
+    };
+    if has_args || stmt.span.from_expansion() {
+        return None;
+    }
+
+    let index = block.stmts.iter().position(|s| s.hir_id == stmt.hir_id)?;
+    let await_stmt = block.stmts[..index]
+        .iter()
+        .find(|s| s.span.contains(await_span) && !s.span.from_expansion())?;
+
+    let mut applicability = Applicability::MaybeIncorrect;
+    let read_stmt = snippet_with_applicability(cx, stmt.span, "..", &mut applicability);
+    let indent = snippet_indent(cx, await_stmt.span)?;
+    Some(vec![
+        (
+            await_stmt.span.shrink_to_lo(),
+            format!("{read_stmt}\n{indent}"),
+        ),
+        (
+            stmt.span.with_lo(block.stmts[index - 1].span.hi()),
+            String::new(),
+        ),
+    ])
+}
+
+/// Whether the paths reaching some point of an async body passed a suspension
+/// point.
+#[derive(Clone, Copy)]
+enum State {
+    /// No path reaches the point, like after a `return`.
+    Diverged,
+    /// Some path reaches the point without suspending the future.
+    Unsuspended,
+    /// Every path reaching the point passed a suspension point, like the `.await`
+    /// at the span.
+    Suspended(Span),
+}
+
+impl State {
+    /// The state where the paths reaching two points join.
+    fn join(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Diverged, state) | (state, Self::Diverged) => state,
+            (Self::Suspended(span), Self::Suspended(_)) => Self::Suspended(span),
+            _ => Self::Unsuspended,
+        }
+    }
+}
+
+/// Walks an async body in evaluation order, collecting the context reads that
+/// are only reached after a suspension point.
+///
+/// Closures and nested async blocks are not entered, as they run at another time
+/// and are checked by themselves. Loops are entered with the state they're
+/// reached with, and are left with the states at their `break`s.
+struct AwaitFlow<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck_results: &'tcx TypeckResults<'tcx>,
+    state: State,
+    /// The loops and labeled blocks being walked, with the states at the `break`s
+    /// leaving them so far.
+    breaks: Vec<(HirId, State)>,
+    reads: Vec<(&'tcx Expr<'tcx>, Span)>,
+}
+
+impl AwaitFlow<'_, '_> {
+    fn is_context_read(&self, expr: &Expr<'_>) -> bool {
+        let def_id = match expr.kind {
+            ExprKind::Call(callee, _) => match *self.typeck_results.expr_ty(callee).kind() {
+                ty::FnDef(def_id, _) => def_id,
+                _ => return false,
+            },
+            ExprKind::MethodCall(..) => {
+                match self.typeck_results.type_dependent_def_id(expr.hir_id) {
+                    Some(def_id) => def_id,
+                    None => return false,
+                }
+            }
+            _ => return false,
+        };
+        is_context_read(self.cx, def_id)
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for AwaitFlow<'_, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Match(future, _, MatchSource::AwaitDesugar) => {
+                self.visit_expr(future);
+                if let State::Unsuspended = self.state {
+                    self.state = State::Suspended(expr.span);
+                }
+            }
+            ExprKind::If(cond, then, els) => {
+                self.visit_expr(cond);
+                let start = self.state;
+                self.visit_expr(then);
+                let then_state = std::mem::replace(&mut self.state, start);
+                if let Some(els) = els {
+                    self.visit_expr(els);
+                }
+                self.state = then_state.join(self.state);
+            }
+            ExprKind::Match(scrutinee, arms, _) => {
+                self.visit_expr(scrutinee);
+                let start = self.state;
+                let mut end = State::Diverged;
+                for arm in arms {
+                    self.state = start;
+                    self.visit_arm(arm);
+                    end = end.join(self.state);
+                }
+                self.state = end;
+            }
+            ExprKind::Binary(op, lhs, rhs) if matches!(op.node, BinOpKind::And | BinOpKind::Or) => {
+                self.visit_expr(lhs);
+                let state = self.state;
+                self.visit_expr(rhs);
+                self.state = self.state.join(state);
+            }
+            ExprKind::Loop(block, ..) => {
+                // A loop is only left through its `break`s, like the one desugared
+                // from the condition of a `while` loop
+                self.breaks.push((expr.hir_id, State::Diverged));
+                self.visit_block(block);
+                self.state = self
+                    .breaks
+                    .pop()
+                    .map_or(State::Diverged, |(_, state)| state);
+            }
+            ExprKind::Block(block, Some(_)) => {
+                // The `break`s leaving a labeled block target the block itself
+                self.breaks.push((block.hir_id, State::Diverged));
+                self.visit_block(block);
+                let state = self
+                    .breaks
+                    .pop()
+                    .map_or(State::Diverged, |(_, state)| state);
+                self.state = self.state.join(state);
+            }
+            ExprKind::Break(destination, value) => {
+                if let Some(value) = value {
+                    self.visit_expr(value);
+                }
+                if let Ok(target) = destination.target_id
+                    && let Some((_, state)) = self.breaks.iter_mut().rfind(|(id, _)| *id == target)
+                {
+                    *state = state.join(self.state);
+                }
+                self.state = State::Diverged;
+            }
+            ExprKind::Ret(value) => {
+                if let Some(value) = value {
+                    self.visit_expr(value);
+                }
+                self.state = State::Diverged;
+            }
+            ExprKind::Continue(_) => self.state = State::Diverged,
+            _ => {
+                walk_expr(self, expr);
+                if let State::Suspended(await_span) = self.state
+                    && self.is_context_read(expr)
+                {
+                    self.reads.push((expr, await_span));
+                }
+            }
+        }
+    }
+
+    fn visit_local(&mut self, local: &'tcx LetStmt<'tcx>) {
+        if let Some(init) = local.init {
+            self.visit_expr(init);
+        }
+        // The `else` block of a `let` statement diverges
+        if let Some(els) = local.els {
+            let state = self.state;
+            self.visit_block(els);
+            self.state = state;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
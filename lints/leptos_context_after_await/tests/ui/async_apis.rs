@@ -0,0 +1,40 @@
+use leptos::prelude::*;
+use leptos::task::{spawn_local, spawn_local_scoped, tick};
+
+#[derive(Clone)]
+struct Theme;
+
+fn main() {
+    spawn_local(async {
+        tick().await;
+        let _theme = expect_context::<Theme>();
+    });
+
+    let _action = Action::new(|input: &i32| {
+        let input = *input;
+        async move {
+            tick().await;
+            let _theme = expect_context::<Theme>();
+            input
+        }
+    });
+
+    let _resource = Resource::new(
+        || 1,
+        |_| async {
+            tick().await;
+            use_context::<Theme>().is_some()
+        },
+    );
+
+    let _suspend = Suspend::new(async {
+        tick().await;
+        let _theme = expect_context::<Theme>();
+    });
+
+    // Scoped spawns restore the owner every time the future is polled
+    spawn_local_scoped(async {
+        tick().await;
+        let _theme = expect_context::<Theme>();
+    });
+}
@@ -0,0 +1,160 @@
+use leptos::prelude::*;
+use leptos::router::hooks::use_navigate;
+use leptos::task::{spawn_local, tick};
+
+#[derive(Clone)]
+struct Theme;
+
+async fn load() -> bool {
+    tick().await;
+    true
+}
+
+async fn after_await() {
+    tick().await;
+    let _theme = expect_context::<Theme>();
+}
+
+async fn before_await() {
+    let _theme = expect_context::<Theme>();
+    tick().await;
+}
+
+async fn after_await_in_condition() {
+    if load().await {
+        let _theme = use_context::<Theme>();
+    }
+}
+
+async fn after_await_in_both_branches(flag: bool) {
+    if flag {
+        tick().await;
+    } else {
+        load().await;
+    }
+    let _navigate = use_navigate();
+}
+
+async fn after_await_in_one_branch(flag: bool) {
+    // Contexts that can be read without suspending don't trigger the lint
+    if flag {
+        tick().await;
+    }
+    let _theme = expect_context::<Theme>();
+}
+
+async fn after_await_in_match(value: Option<i32>) {
+    match value {
+        Some(_) => tick().await,
+        None => return,
+    }
+    let _theme = expect_context::<Theme>();
+}
+
+async fn after_try() -> Result<(), ()> {
+    let value = Some(1).ok_or(())?;
+    let _theme = expect_context::<Theme>();
+    if value > 0 && load().await {
+        return Ok(());
+    }
+    let _theme = expect_context::<Theme>();
+    Ok(())
+}
+
+async fn in_loop() {
+    // The first iteration reads the context before suspending
+    for _ in 0..3 {
+        let _theme = expect_context::<Theme>();
+        tick().await;
+    }
+
+    while load().await {
+        let _theme = expect_context::<Theme>();
+    }
+}
+
+async fn next_message() -> Option<i32> {
+    tick().await;
+    Some(1)
+}
+
+async fn after_while_loop() {
+    while let Some(_message) = next_message().await {}
+    let _theme = expect_context::<Theme>();
+}
+
+async fn after_loop(flag: bool) {
+    loop {
+        tick().await;
+        if flag {
+            break;
+        }
+    }
+    let _theme = expect_context::<Theme>();
+}
+
+async fn after_loop_left_before_await(flag: bool) {
+    // Loops left before suspending don't trigger the lint
+    loop {
+        if flag {
+            break;
+        }
+        tick().await;
+    }
+    let _theme = expect_context::<Theme>();
+
+    'read: {
+        if flag {
+            break 'read;
+        }
+        tick().await;
+    }
+    let _theme = expect_context::<Theme>();
+}
+
+async fn in_closure() {
+    tick().await;
+    // Closures run at another time
+    on_cleanup(|| {
+        let _theme = expect_context::<Theme>();
+    });
+}
+
+fn main() {
+    spawn_local(async {
+        after_await().await;
+        before_await().await;
+        after_await_in_condition().await;
+        after_await_in_both_branches(true).await;
+        after_await_in_one_branch(true).await;
+        after_await_in_match(Some(1)).await;
+        let _ = after_try().await;
+        in_loop().await;
+        after_while_loop().await;
+        after_loop(true).await;
+        after_loop_left_before_await(true).await;
+        in_closure().await;
+    });
+
+    spawn_local(async {
+        let _theme = use_context::<Theme>();
+        tick().await;
+        tick().await;
+        let _other = use_context::<Theme>();
+    });
+
+    spawn_local(async {
+        let Some(_theme) = use_context::<Theme>() else {
+            return;
+        };
+        tick().await;
+    });
+
+    spawn_local(async {
+        tick().await;
+        spawn_local(async {
+            // Nested async blocks are checked by themselves
+            let _theme = expect_context::<Theme>();
+        });
+    });
+}
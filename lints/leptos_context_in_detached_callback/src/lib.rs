@@ -0,0 +1,279 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_errors;
+extern crate rustc_hir;
+extern crate rustc_middle;
+extern crate rustc_span;
+
+use std::ops::ControlFlow;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::fn_def_id;
+use clippy_utils::source::{snippet_indent, snippet_with_applicability};
+use clippy_utils::visitors::{Descend, for_each_expr};
+use leptos_lints_utils::context::is_context_read;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{enclosing_call, paths};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{CaptureBy, Closure, Expr, ExprKind, LetStmt, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_span::Span;
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for contexts read with `use_context`, `expect_context` or the hooks of
+    /// other crates, like `leptos_router::hooks::use_navigate`, inside callbacks
+    /// that the browser calls later, outside of any reactive owner:
+    ///
+    /// - the callbacks of `set_timeout`, `set_interval`,
+    ///   `request_animation_frame`, `request_idle_callback` and their
+    ///   `*_with_handle` variants.
+    /// - the listeners of `window_event_listener` and
+    ///   `window_event_listener_untyped`.
+    /// - the closures of `wasm_bindgen::closure::Closure`.
+    ///
+    /// Callbacks that restore an owner with `Owner::with` are not checked inside
+    /// it.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The callbacks run from the event loop of the browser, when the owner that
+    /// registered them is no longer current. Contexts read there are not found,
+    /// and `expect_context` panics at run-time.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// set_timeout(
+    ///     move || {
+    ///         let toaster = expect_context::<Toaster>();
+    ///         toaster.hide();
+    ///     },
+    ///     Duration::from_secs(3),
+    /// );
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// let toaster = expect_context::<Toaster>();
+    /// set_timeout(
+    ///     move || {
+    ///         toaster.hide();
+    ///     },
+    ///     Duration::from_secs(3),
+    /// );
+    /// ```
+    ///
+    /// When the context is read by a `let` statement of the body of a `move`
+    /// closure registered outside of other closures, the lint suggests moving the
+    /// statement before the one that registers the callback.
+    pub LEPTOS_CONTEXT_IN_DETACHED_CALLBACK,
+    Warn,
+    "Checks for contexts read in callbacks that run without a reactive owner"
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosContextInDetachedCallback {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        let name = if paths::DETACHED_CALLBACKS.matches(cx, def_id) {
+            format!("`{}`", cx.tcx.item_name(def_id))
+        } else if paths::WASM_BINDGEN_CLOSURES.matches(cx, def_id) {
+            format!("`Closure::{}`", cx.tcx.item_name(def_id))
+        } else {
+            return;
+        };
+        if !should_lint(cx.tcx, Side::Client) {
+            return;
+        }
+
+        let Some((call, args)) = enclosing_call(cx, expr) else {
+            return;
+        };
+        for arg in args {
+            let callback = peel_boxes(cx, arg);
+            let Some(body) = closure_body(cx, expr, callback) else {
+                continue;
+            };
+            for read in context_reads(cx, body) {
+                span_lint_and_then(
+                    cx,
+                    LEPTOS_CONTEXT_IN_DETACHED_CALLBACK,
+                    read.span,
+                    "context read in a callback that runs without a reactive owner",
+                    |diag| {
+                        diag.span_note(
+                            expr.span,
+                            format!("the callback passed to {name} is called by the browser later"),
+                        );
+                        if let Some(edits) = hoist_read(cx, read, callback, call) {
+                            diag.multipart_suggestion(
+                                "read the context before registering the callback",
+                                edits,
+                                Applicability::MaybeIncorrect,
+                            );
+                        } else {
+                            diag.help("read the context outside of the callback and move it in");
+                        }
+                        diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_context_in_detached_callback#readme");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Peel the boxes and casts of callbacks passed as trait objects, like
+/// `Box::new(|| {}) as Box<dyn Fn()>`.
+fn peel_boxes<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    loop {
+        expr = match expr.kind {
+            ExprKind::Cast(inner, _) => inner,
+            ExprKind::Call(_, [inner]) if cx.typeck_results().expr_ty(expr).is_box() => inner,
+            _ => return expr,
+        };
+    }
+}
+
+/// Body of a callback, if it's a closure defined in the same function as the
+/// call that registers it, so it can be checked with the same typeck results.
+fn closure_body<'tcx>(
+    cx: &LateContext<'tcx>,
+    callee: &Expr<'_>,
+    callback: &Expr<'_>,
+) -> Option<&'tcx Expr<'tcx>> {
+    let ty::Closure(closure_def_id, _) = *cx.typeck_results().expr_ty(callback).kind() else {
+        return None;
+    };
+    let typeck_root = |def_id: DefId| cx.tcx.typeck_root_def_id(def_id);
+    let call_owner = cx.tcx.hir_enclosing_body_owner(callee.hir_id).to_def_id();
+    if !closure_def_id.is_local() || typeck_root(closure_def_id) != typeck_root(call_owner) {
+        return None;
+    }
+    let body = cx
+        .tcx
+        .hir_maybe_body_owned_by(closure_def_id.expect_local())?;
+    Some(body.value)
+}
+
+/// Contexts read by the body of a callback. Closures that restore an owner with
+/// `Owner::with` are not entered, nor the callbacks of other detached callbacks,
+/// which are checked by themselves.
+fn context_reads<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>) -> Vec<&'tcx Expr<'tcx>> {
+    let mut reads = Vec::new();
+    for_each_expr(cx, body, |expr| {
+        let Some(def_id) = fn_def_id(cx, expr) else {
+            return ControlFlow::<(), _>::Continue(Descend::Yes);
+        };
+        if paths::OWNER_WITH.matches(cx, def_id)
+            || paths::DETACHED_CALLBACKS.matches(cx, def_id)
+            || paths::WASM_BINDGEN_CLOSURES.matches(cx, def_id)
+        {
+            return ControlFlow::Continue(Descend::No);
+        }
+        if is_context_read(cx, def_id) {
+            reads.push(expr);
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+    reads
+}
+
+/// Edits moving a `let` statement of the body of a `move` closure that reads a
+/// context before the statement that registers the closure as a callback.
+fn hoist_read(
+    cx: &LateContext<'_>,
+    read: &Expr<'_>,
+    callback: &Expr<'_>,
+    call: &Expr<'_>,
+) -> Option<Vec<(Span, String)>> {
+    let ExprKind::Closure(&Closure {
+        capture_clause: CaptureBy::Value { .. },
+        body,
+        ..
+    }) = callback.kind
+    else {
+        return None;
+    };
+    let ExprKind::Block(body_block, _) = cx.tcx.hir_body(body).value.kind else {
+        return None;
+    };
+
+    let has_args = match read.kind {
+        ExprKind::Call(_, args) | ExprKind::MethodCall(_, _, args, _) => !args.is_empty(),
+        _ => true,
+    };
+    let Node::LetStmt(&LetStmt {
+        els: None, hir_id, ..
+    }) = cx.tcx.parent_hir_node(read.hir_id)
+    else {
+        return None;
+    };
+    let Node::Stmt(stmt) = cx.tcx.parent_hir_node(hir_id) else {
+        return None;
+    };
+    // Callbacks registered inside of closures, like other callbacks, may not
+    // have an owner either
+    let call_owner = cx.tcx.hir_enclosing_body_owner(call.hir_id);
+    if has_args || stmt.span.from_expansion() || cx.tcx.is_closure_like(call_owner.to_def_id()) {
+        return None;
+    }
+    let index = body_block
+        .stmts
+        .iter()
+        .position(|s| s.hir_id == stmt.hir_id)?;
+
+    let (_, call_stmt) = cx
+        .tcx
+        .hir_parent_iter(call.hir_id)
+        .find(|(_, node)| matches!(node, Node::Stmt(_)))?;
+    let Node::Stmt(call_stmt) = call_stmt else {
+        return None;
+    };
+    if call_stmt.span.from_expansion() {
+        return None;
+    }
+
+    // The statement is removed with the whitespace before it, or after it when
+    // it's the first one of the closure
+    let removal = if let Some(prev) = index.checked_sub(1) {
+        stmt.span.with_lo(body_block.stmts[prev].span.hi())
+    } else {
+        let next = body_block
+            .stmts
+            .get(1)
+            .map(|s| s.span)
+            .or(body_block.expr.map(|e| e.span))?;
+        stmt.span.with_hi(next.lo())
+    };
+
+    let mut applicability = Applicability::MaybeIncorrect;
+    let read_stmt = snippet_with_applicability(cx, stmt.span, "..", &mut applicability);
+    let indent = snippet_indent(cx, call_stmt.span)?;
+    Some(vec![
+        (
+            call_stmt.span.shrink_to_lo(),
+            format!("{read_stmt}\n{indent}"),
+        ),
+        (removal, String::new()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
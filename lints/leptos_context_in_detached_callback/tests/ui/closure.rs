@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+use leptos::wasm_bindgen::prelude::*;
+
+#[derive(Clone, Copy)]
+struct Toaster(RwSignal<bool>);
+
+impl Toaster {
+    fn hide(self) {
+        self.0.set(false);
+    }
+}
+
+fn main() {
+    let on_message = Closure::<dyn Fn(JsValue)>::new(move |_: JsValue| {
+        let toaster = expect_context::<Toaster>();
+        toaster.hide();
+    });
+    on_message.forget();
+
+    let on_load = Closure::<dyn FnOnce()>::once(move || {
+        let _toaster = use_context::<Toaster>();
+    });
+    on_load.forget();
+
+    let on_error = Closure::wrap(Box::new(move || {
+        let toaster = expect_context::<Toaster>();
+        toaster.hide();
+    }) as Box<dyn Fn()>);
+    on_error.forget();
+
+    // Contexts read before creating the closure don't trigger the lint
+    let toaster = expect_context::<Toaster>();
+    let on_close = Closure::<dyn Fn()>::new(move || toaster.hide());
+    on_close.forget();
+}
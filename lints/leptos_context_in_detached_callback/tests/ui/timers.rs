@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use leptos::ev;
+use leptos::prelude::*;
+use leptos::router::hooks::use_location;
+
+#[derive(Clone, Copy)]
+struct Toaster(RwSignal<bool>);
+
+impl Toaster {
+    fn hide(self) {
+        self.0.set(false);
+    }
+}
+
+fn main() {
+    set_timeout(
+        move || {
+            let toaster = expect_context::<Toaster>();
+            toaster.hide();
+        },
+        Duration::from_secs(3),
+    );
+
+    let _handle = set_timeout_with_handle(
+        || {
+            if let Some(toaster) = use_context::<Toaster>() {
+                toaster.hide();
+            }
+        },
+        Duration::from_secs(3),
+    );
+
+    set_interval(
+        move || {
+            let _location = use_location();
+        },
+        Duration::from_secs(60),
+    );
+
+    request_animation_frame(move || {
+        let toaster = expect_context::<Toaster>();
+        toaster.hide();
+    });
+
+    request_idle_callback(|| expect_context::<Toaster>().hide());
+
+    let _listener = window_event_listener(ev::keydown, move |_| {
+        let toaster = expect_context::<Toaster>();
+        toaster.hide();
+    });
+
+    let _listener = window_event_listener_untyped("resize", move |_| {
+        expect_context::<Toaster>().hide();
+    });
+
+    // Callbacks defined before they're registered
+    let hide = move || expect_context::<Toaster>().hide();
+    set_timeout(hide, Duration::from_secs(3));
+
+    // Nested callbacks are only reported once
+    request_animation_frame(move || {
+        request_animation_frame(move || {
+            let toaster = expect_context::<Toaster>();
+            toaster.hide();
+        });
+    });
+
+    // Contexts read before registering the callback don't trigger the lint
+    let toaster = expect_context::<Toaster>();
+    set_timeout(move || toaster.hide(), Duration::from_secs(3));
+
+    // Callbacks that restore an owner don't trigger the lint
+    let owner = Owner::current().unwrap();
+    set_timeout(
+        move || {
+            owner.with(|| expect_context::<Toaster>().hide());
+        },
+        Duration::from_secs(3),
+    );
+}
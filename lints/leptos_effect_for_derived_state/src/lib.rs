@@ -0,0 +1,263 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_errors;
+extern crate rustc_hir;
+extern crate rustc_span;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{fn_def_id, path_to_local};
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::signal::{
+    SignalPlace, signal_accesses, signal_place, split_signal, tracked_signal_reads,
+};
+use leptos_lints_utils::{enclosing_call, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Node, Pat, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::{Span, Symbol};
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for effects created with `Effect::new`, `Effect::new_isomorphic`,
+    /// `RenderEffect::new` or the deprecated `create_effect` whose closures only set
+    /// signals with `.set()` to values computed from signals that they read with
+    /// `.get()`, `.with()` or `.read()`.
+    ///
+    /// Effects with other statements, like logging or `if` expressions, are not
+    /// reported. Effects that write to the signals that they read are reported by
+    /// `leptos_effect_writes_read_signal` instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Effects are meant to synchronize the reactive system with the world outside
+    /// of it, like the DOM or a server. Using them to keep a signal in sync with
+    /// others creates an extra signal and effect, renders the views that read the
+    /// signal once with a stale value each time that its sources change, and
+    /// leaves the value without computing on the server, where effects don't run.
+    ///
+    /// A memo, or a closure for cheap values, derives the value from its sources
+    /// when it's read.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// let (count, set_count) = signal(0);
+    /// let (doubled, set_doubled) = signal(0);
+    /// Effect::new(move |_| set_doubled.set(count.get() * 2));
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// let (count, set_count) = signal(0);
+    /// let doubled = Memo::new(move |_| count.get() * 2);
+    /// ```
+    ///
+    /// Or, with a derived signal:
+    ///
+    /// ```rust
+    /// let doubled = move || count.get() * 2;
+    /// ```
+    pub LEPTOS_EFFECT_FOR_DERIVED_STATE,
+    Warn,
+    "Checks for effects that only set signals to values derived from other signals"
+}
+
+/// A call of `.set()` of an effect, with a value computed from tracked signals.
+struct DerivedWrite<'tcx> {
+    call: &'tcx Expr<'tcx>,
+    signal: &'tcx Expr<'tcx>,
+    value: &'tcx Expr<'tcx>,
+    place: SignalPlace,
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosEffectForDerivedState {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        // Effects only run in the browser
+        if !paths::EFFECTS.matches(cx, def_id) || !should_lint(cx.tcx, Side::Client) {
+            return;
+        }
+
+        let Some((call, [fun])) = enclosing_call(cx, expr) else {
+            return;
+        };
+        let ExprKind::Closure(closure) = fun.kind else {
+            return;
+        };
+        let body = cx.tcx.hir_body(closure.body);
+        // Effects that use their previous value keep state between runs
+        if !body
+            .params
+            .iter()
+            .all(|param| matches!(param.pat.kind, PatKind::Wild))
+        {
+            return;
+        }
+
+        let Some(writes) = derived_writes(cx, body.value) else {
+            return;
+        };
+        let reads = tracked_signal_reads(cx, body.value);
+        if writes
+            .iter()
+            .any(|write| reads.iter().any(|(place, _)| *place == write.place))
+        {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            LEPTOS_EFFECT_FOR_DERIVED_STATE,
+            call.span,
+            "effect used to derive the values of signals from other signals",
+            |diag| {
+                diag.span_note(
+                    writes.iter().map(|write| write.call.span).collect::<Vec<_>>(),
+                    "the effect only sets signals to values computed from the signals that it reads",
+                );
+                match memo_suggestion(cx, call, &writes) {
+                    Some((span, sugg, applicability)) => {
+                        diag.span_suggestion_verbose(
+                            span,
+                            "compute the value in a memo, which replaces the signal",
+                            sugg,
+                            applicability,
+                        );
+                    }
+                    None => {
+                        diag.help(
+                            "compute the values with `Memo::new` instead, or derive them in \
+                             closures or with `Signal::derive`",
+                        );
+                    }
+                }
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_effect_for_derived_state#readme");
+            },
+        );
+    }
+}
+
+/// Writes of the body of an effect, when it only sets signals to values computed
+/// from tracked signals.
+fn derived_writes<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Expr<'tcx>,
+) -> Option<Vec<DerivedWrite<'tcx>>> {
+    let (stmts, tail) = match body.kind {
+        ExprKind::Block(block, _) => (block.stmts, block.expr),
+        _ => (&[][..], Some(body)),
+    };
+    let writes = stmts
+        .iter()
+        .map(|stmt| match stmt.kind {
+            StmtKind::Semi(expr) | StmtKind::Expr(expr) => Some(expr),
+            StmtKind::Let(_) | StmtKind::Item(_) => None,
+        })
+        .chain(tail.map(Some))
+        .map(|expr| derived_write(cx, expr?))
+        .collect::<Option<Vec<_>>>()?;
+    (!writes.is_empty()).then_some(writes)
+}
+
+/// Call of `.set()` with a value computed from tracked signals.
+fn derived_write<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<DerivedWrite<'tcx>> {
+    let ExprKind::MethodCall(_, signal, [value], _) = expr.kind else {
+        return None;
+    };
+    if expr.span.from_expansion()
+        || !fn_def_id(cx, expr).is_some_and(|def_id| paths::SIGNAL_SET.matches(cx, def_id))
+        || tracked_signal_reads(cx, value).is_empty()
+        || !signal_accesses(cx, value, &paths::SIGNAL_WRITES).is_empty()
+    {
+        return None;
+    }
+    Some(DerivedWrite {
+        call: expr,
+        signal,
+        value,
+        place: signal_place(cx, signal)?,
+    })
+}
+
+/// Replacement of the statement of an effect with a single write by a memo named
+/// like the signal that it writes, like `let doubled = Memo::new(move |_| ..);`.
+fn memo_suggestion(
+    cx: &LateContext<'_>,
+    call: &Expr<'_>,
+    writes: &[DerivedWrite<'_>],
+) -> Option<(Span, String, Applicability)> {
+    let [write] = writes else {
+        return None;
+    };
+    let Node::Stmt(stmt) = cx.tcx.parent_hir_node(call.hir_id) else {
+        return None;
+    };
+    if !matches!(stmt.kind, StmtKind::Semi(_)) || stmt.span.from_expansion() {
+        return None;
+    }
+    let name = signal_name(cx, write.signal)?;
+    let mut applicability = Applicability::MaybeIncorrect;
+    let (value, _) = snippet_with_context(
+        cx,
+        write.value.span,
+        stmt.span.ctxt(),
+        "..",
+        &mut applicability,
+    );
+    Some((
+        stmt.span,
+        format!("let {name} = Memo::new(move |_| {value});"),
+        applicability,
+    ))
+}
+
+/// Name to give to a memo that replaces a signal defined by a `let` statement:
+/// the name of the signal, or of its read half when it's split.
+fn signal_name(cx: &LateContext<'_>, signal: &Expr<'_>) -> Option<Symbol> {
+    let binding = path_to_local(signal)?;
+    let pat = match split_signal(cx, binding) {
+        Some((
+            Pat {
+                kind: PatKind::Tuple([read, ..], _),
+                ..
+            },
+            _,
+        )) => read,
+        Some(_) => return None,
+        None => {
+            let Node::Pat(pat) = cx.tcx.hir_node(binding) else {
+                return None;
+            };
+            if !matches!(cx.tcx.parent_hir_node(binding), Node::LetStmt(_)) {
+                return None;
+            }
+            pat
+        }
+    };
+    match pat.kind {
+        PatKind::Binding(_, _, ident, None) => Some(ident.name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
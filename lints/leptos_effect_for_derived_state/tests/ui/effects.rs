@@ -0,0 +1,38 @@
+use leptos::prelude::*;
+
+fn main() {
+    let count = RwSignal::new(0);
+    let doubled = RwSignal::new(0);
+    let halved = RwSignal::new(0);
+
+    Effect::new(move |_| doubled.set(count.get() * 2));
+
+    Effect::new(move || {
+        doubled.set(count.get() * 2);
+        halved.set(count.get() / 2);
+    });
+
+    RenderEffect::new(move |_| {
+        halved.set(count.with(|count| count / 2));
+    });
+
+    #[allow(deprecated)]
+    create_effect(move |_| halved.set(count.get() / 2));
+
+    // Effects with other side effects
+    Effect::new(move || {
+        leptos::logging::log!("{}", count.get());
+        doubled.set(count.get() * 2);
+    });
+
+    // Values that don't come from tracked signals
+    Effect::new(move || doubled.set(count.get_untracked() * 2));
+
+    Effect::new(move || doubled.set(untrack(|| count.get()) * 2));
+
+    // Effects that use their previous value
+    Effect::new(move |prev: Option<()>| doubled.set(count.get() + i32::from(prev.is_some())));
+
+    // Signals written with other methods
+    Effect::new(move || doubled.update(|doubled| *doubled = count.get() * 2));
+}
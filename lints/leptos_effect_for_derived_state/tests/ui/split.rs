@@ -0,0 +1,33 @@
+use leptos::prelude::*;
+
+#[derive(Clone, Copy)]
+struct Totals {
+    items: RwSignal<i32>,
+}
+
+fn main() {
+    let (count, set_count) = signal(0);
+    let (doubled, set_doubled) = signal(0);
+    Effect::new(move |_| set_doubled.set(count.get() * 2));
+
+    let (name, set_name) = RwSignal::new(String::new()).split();
+    let (greeting, set_greeting) = signal(String::new());
+    Effect::new(move || {
+        set_greeting.set(format!("Hello, {}!", name.get()));
+    });
+
+    // Signals that can't be named by a memo
+    let (_, set_total) = signal(0);
+    Effect::new(move || set_total.set(count.get() + 1));
+
+    let totals = Totals {
+        items: RwSignal::new(0),
+    };
+    Effect::new(move || totals.items.set(count.get()));
+
+    // Effects that write to the signals that they read
+    Effect::new(move || set_count.set(count.get() + 1));
+
+    set_name.set(String::new());
+    let _values = (doubled.get_untracked(), greeting.get_untracked());
+}
@@ -0,0 +1,137 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_data_structures;
+extern crate rustc_hir;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::signal::{signal_accesses, tracked_signal_reads};
+use leptos_lints_utils::{enclosing_call, paths};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for the closures of effects created with `Effect::new`,
+    /// `Effect::new_isomorphic`, `RenderEffect::new` or the deprecated
+    /// `create_effect` that write with `.set()`, `.update()` or `.write()` to a
+    /// signal that they read with `.get()`, `.with()` or `.read()`.
+    ///
+    /// For `Effect::watch`, the signals read by the dependency closure are checked
+    /// against the signals written by the handler.
+    ///
+    /// Reads that the effect doesn't track are ignored, like the ones of `untrack`,
+    /// async blocks, event listeners, or callbacks of `set_timeout` and the other
+    /// functions that call them later. Writes are checked wherever they are, as
+    /// they run the effect again even when they happen later.
+    ///
+    /// Signals are told apart by the variables and fields that hold them. The read
+    /// and write halves of signals created with `signal()` or `RwSignal::split`
+    /// are known to be the same signal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An effect runs again each time that a signal that it tracks changes. When it
+    /// writes to one of them, it triggers itself, so it runs in an endless loop
+    /// or, at best, once more than needed.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// let (count, set_count) = signal(0);
+    /// let (doubled, set_doubled) = signal(0);
+    /// Effect::new(move || {
+    ///     set_doubled.set(count.get() * 2);
+    ///     set_count.set(count.get() + 1);
+    /// });
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// let (count, set_count) = signal(0);
+    /// let doubled = Memo::new(move |_| count.get() * 2);
+    /// ```
+    pub LEPTOS_EFFECT_WRITES_READ_SIGNAL,
+    Warn,
+    "Checks for effects that write to the signals that they read"
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosEffectWritesReadSignal {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        let is_watch = paths::EFFECT_WATCH.matches(cx, def_id);
+        // Effects only run in the browser
+        if !is_watch && !paths::EFFECTS.matches(cx, def_id) || !should_lint(cx.tcx, Side::Client) {
+            return;
+        }
+
+        let Some((_, args)) = enclosing_call(cx, expr) else {
+            return;
+        };
+        let (tracked, effect) = match args {
+            [dependency_fn, handler, ..] if is_watch => (dependency_fn, handler),
+            [fun, ..] => (fun, fun),
+            [] => return,
+        };
+        let (Some(tracked), Some(effect)) = (closure_body(cx, tracked), closure_body(cx, effect))
+        else {
+            return;
+        };
+
+        let reads = tracked_signal_reads(cx, tracked);
+        let writes = signal_accesses(cx, effect, &paths::SIGNAL_WRITES);
+        let mut reported = FxHashSet::default();
+        for (place, write) in &writes {
+            let Some((_, read)) = reads.iter().find(|(read_place, _)| read_place == place) else {
+                continue;
+            };
+            if !reported.insert(place) {
+                continue;
+            }
+            span_lint_and_then(
+                cx,
+                LEPTOS_EFFECT_WRITES_READ_SIGNAL,
+                write.span,
+                "effect writes to a signal that it reads",
+                |diag| {
+                    diag.span_note(
+                        read.span,
+                        "the signal is tracked here, so each write runs the effect again",
+                    );
+                    diag.help(
+                        "derive the value with `Memo::new` or `Signal::derive` instead, or read \
+                         the signal with `.get_untracked()` if the effect must not track it",
+                    );
+                    diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_effect_writes_read_signal#readme");
+                },
+            );
+        }
+    }
+}
+
+/// Body of a closure passed to an effect.
+fn closure_body<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    match expr.kind {
+        ExprKind::Closure(closure) => Some(cx.tcx.hir_body(closure.body).value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+fn main() {
+    let count = RwSignal::new(0);
+    let total = RwSignal::new(0);
+
+    Effect::new(move || {
+        count.set(count.get() + 1);
+    });
+
+    Effect::new(move |_| {
+        if count.with(|count| *count < 10) {
+            count.update(|count| *count += 1);
+        }
+    });
+
+    RenderEffect::new(move |_| {
+        let doubled = *count.read() * 2;
+        *count.write() = doubled;
+    });
+
+    #[allow(deprecated)]
+    create_effect(move |_| count.set(count.get() * 2));
+
+    // The handler of `Effect::watch` writes a signal tracked by its dependencies
+    Effect::watch(
+        move || count.get(),
+        move |count_value, _, _| count.set(count_value + 1),
+        false,
+    );
+
+    // Writes that run later still run the effect again
+    Effect::new(move || {
+        let value = count.get();
+        spawn_local(async move { count.set(value + 1) });
+    });
+
+    // Effects writing other signals
+    Effect::new(move || total.set(count.get() * 2));
+
+    // Signals read without tracking them
+    Effect::new(move || count.set(count.get_untracked() + 1));
+
+    // Only the dependencies of `Effect::watch` are tracked
+    Effect::watch(
+        move || total.get(),
+        move |_, _, _| count.set(count.get() + 1),
+        false,
+    );
+
+    // Signals read by code that runs later or without tracking them
+    Effect::new(move || spawn_local(async move { count.set(count.get() + 1) }));
+
+    Effect::new(move || count.set(untrack(|| count.get()) + 1));
+
+    Effect::new(move || {
+        set_timeout(move || count.set(count.get() + 1), Duration::from_secs(1));
+    });
+
+    Effect::new(move || {
+        let _view = view! {
+            <button on:click={move |_| count.set(count.get() + 1)}>{total}</button>
+        };
+    });
+}
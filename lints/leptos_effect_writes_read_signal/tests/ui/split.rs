@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+
+#[derive(Clone, Copy)]
+struct Form {
+    name: RwSignal<String>,
+    saved: RwSignal<bool>,
+}
+
+fn main() {
+    let (count, set_count) = signal(0);
+    Effect::new(move || set_count.set(count.get() + 1));
+
+    let (label, set_label) = RwSignal::new(String::new()).split();
+    Effect::new(move || set_label.set(label.get().trim().to_string()));
+
+    let form = Form {
+        name: RwSignal::new(String::new()),
+        saved: RwSignal::new(false),
+    };
+    Effect::new(move || {
+        let name = form.name.get();
+        form.name.set(name.to_uppercase());
+    });
+
+    // Signals split from different calls
+    let (other, _) = signal(0);
+    Effect::new(move || set_count.set(other.get()));
+
+    // Different fields
+    Effect::new(move || {
+        if form.saved.get() {
+            form.name.update(String::clear);
+        }
+    });
+}
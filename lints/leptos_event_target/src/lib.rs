@@ -1,18 +1,41 @@
 #![feature(rustc_private)]
 #![warn(unused_extern_crates)]
 
+extern crate rustc_errors;
 extern crate rustc_hir;
+extern crate rustc_middle;
 
-use clippy_utils::diagnostics::span_lint_and_help;
-use rustc_hir::{Expr, ExprKind, QPath};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use leptos_lints_utils::event::event_origin;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{enclosing_call, expr_ty_is_any_of, paths, sugg};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, GenericArg, QPath};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use serde::Deserialize;
 
-dylint_linting::declare_late_lint! {
+dylint_linting::impl_late_lint! {
     /// ### What it does
     ///
     /// Warn about usages of `event_target`. The purpose of this lint is
     /// to make users know that the function may panic under certain circumstances.
     ///
+    /// Events are only dispatched in the browser, so the lint is skipped when
+    /// compiling for the server with the `ssr` feature.
+    ///
+    /// The event passed to the function is followed to tell where it comes from.
+    /// Events received by event listeners, attached with `on:` attributes in the
+    /// `view!` macro, `.on()` or `window_event_listener`, and events of the types
+    /// that the browser dispatches to elements, like `MouseEvent` or
+    /// `KeyboardEvent`, always have a target, so calls passing them are allowed.
+    /// Events created by code, like with `Event::new`, events of types that are
+    /// not dispatched to elements, like `CustomEvent` or `FetchEvent`, and events
+    /// whose origin is unknown, like the ones received as arguments by functions
+    /// that aren't event listeners, are reported, pointing to where they come
+    /// from.
+    ///
     /// ### Why is this bad?
     ///
     /// The function `leptos::prelude::event_target` will panic at run-time if the
@@ -40,33 +63,65 @@ dylint_linting::declare_late_lint! {
     /// Use instead:
     ///
     /// ```rust,ignore
-    /// let target = match event.target() {
-    ///     Some(t) => t.dyn_into::<web_sys::Document>().ok(),
-    ///     None => {
-    ///        // handle the case where there is no target
-    ///        todo!()
-    ///     },
-    /// };
+    /// let target = event
+    ///     .target()
+    ///     .and_then(|t| t.dyn_into::<web_sys::Document>().ok());
     /// ```
     ///
+    /// The lint suggests this replacement, leaving to you the handling of the
+    /// `None` case.
+    ///
     /// Or allow the lint if you're sure that the event has a target:
     ///
     /// ```rust,ignore
     /// #[allow(leptos_event_target)]
     /// let target = leptos::prelude::event_target::<web_sys::Document>(&event);
     /// ```
+    ///
+    /// ### Configuration
+    ///
+    /// - `allowed_event_types`: types of events known to always have a target, like
+    ///   the ones dispatched by the browser to the elements where the handlers are
+    ///   attached. Calls passing these events are allowed. Default: `[]`.
+    ///
+    /// ```toml
+    /// [leptos_event_target]
+    /// allowed_event_types = ["web_sys::MouseEvent"]
+    /// ```
     pub LEPTOS_EVENT_TARGET,
     Warn,
-    "Warn about usages of `leptos::prelude::event_target`"
+    "Warn about usages of `leptos::prelude::event_target`",
+    LeptosEventTarget::new()
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed_event_types: Vec<String>,
+}
+
+pub struct LeptosEventTarget {
+    config: Config,
+}
+
+impl LeptosEventTarget {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+        }
+    }
+}
+
+impl Default for LeptosEventTarget {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'tcx> LateLintPass<'tcx> for LeptosEventTarget {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
-        let ExprKind::Call(func, _) = expr.kind else {
-            return;
-        };
-
-        let ExprKind::Path(ref qpath) = func.kind else {
+        let ExprKind::Path(ref qpath) = expr.kind else {
             return;
         };
 
@@ -74,23 +129,69 @@ impl<'tcx> LateLintPass<'tcx> for LeptosEventTarget {
             return;
         };
 
-        if cx.tcx.item_name(def_id).as_str() != "event_target" {
+        // Events are only dispatched in the browser
+        if !paths::EVENT_TARGET.matches(cx, def_id) || !should_lint(cx.tcx, Side::Client) {
             return;
         }
 
-        let QPath::Resolved(_, path) = qpath else {
-            return;
+        let call = enclosing_call(cx, expr);
+        let origin = match call {
+            Some((_, [event])) => {
+                let origin = event_origin(cx, event);
+                if origin.has_target()
+                    || expr_ty_is_any_of(cx, event, &self.config.allowed_event_types)
+                {
+                    return;
+                }
+                Some((event, origin))
+            }
+            _ => None,
         };
 
-        span_lint_and_help(
+        span_lint_and_then(
             cx,
             LEPTOS_EVENT_TARGET,
-            path.span,
+            expr.span,
             "use of `leptos::prelude::event_target`",
-            None,
-            "this function can panic at run-time if the target doesn't have an event. \
-                  For further information visit \
-                  https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_event_target#readme",
+            |diag| {
+                diag.help("this function panics at run-time if the event doesn't have a target");
+                if let Some((event, origin)) = origin {
+                    origin.explain(cx, diag, event);
+                }
+                if let Some((call, [event])) = call {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let event = sugg::receiver_snippet(cx, event, &mut applicability);
+                    let segment = match qpath {
+                        QPath::Resolved(_, path) => path.segments.last(),
+                        QPath::TypeRelative(_, segment) => Some(*segment),
+                        QPath::LangItem(..) => None,
+                    };
+                    let explicit_ty = segment
+                        .and_then(|segment| segment.args)
+                        .map(|args| args.args);
+                    let target_ty = if let Some([GenericArg::Type(ty)]) = explicit_ty {
+                        snippet_with_applicability(cx, ty.span, "_", &mut applicability)
+                            .into_owned()
+                    } else {
+                        with_no_trimmed_paths!(
+                            cx.typeck_results()
+                                .node_args(expr.hir_id)
+                                .type_at(0)
+                                .to_string()
+                        )
+                    };
+                    diag.span_suggestion(
+                        call.span,
+                        "handle the event not having a target",
+                        format!("{event}.target().and_then(|t| t.dyn_into::<{target_ty}>().ok())"),
+                        applicability,
+                    );
+                }
+                diag.help(
+                    "for further information visit \
+                     https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_event_target#readme",
+                );
+            },
         );
     }
 }
@@ -99,6 +200,13 @@ impl<'tcx> LateLintPass<'tcx> for LeptosEventTarget {
 mod tests {
     #[test]
     fn ui_examples() {
-        dylint_testing::ui_test_examples(env!("CARGO_PKG_NAME"));
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME"))
+            .dylint_toml(
+                r#"
+                [leptos_event_target]
+                allowed_event_types = ["leptos::web_sys::MouseEvent"]
+                "#,
+            )
+            .run();
     }
 }
@@ -1,5 +0,0 @@
-pub mod prelude {
-    pub fn event_target<T>() -> Option<T> {
-        None
-    }
-}
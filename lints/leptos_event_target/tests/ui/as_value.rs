@@ -0,0 +1,14 @@
+use leptos::prelude::event_target;
+use leptos::web_sys::{Event, HtmlInputElement};
+
+fn main() {
+    let events = [Event::new("input").unwrap(), Event::new("change").unwrap()];
+
+    let _inputs: Vec<HtmlInputElement> = events.iter().map(event_target).collect();
+
+    let read: fn(&Event) -> HtmlInputElement = leptos::prelude::event_target;
+    let _input = read(&events[0]);
+
+    let callback = leptos::prelude::event_target::<HtmlInputElement>;
+    let _input = callback(&events[1]);
+}
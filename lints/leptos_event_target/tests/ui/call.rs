@@ -1,12 +1,24 @@
 use leptos::prelude;
 use leptos::prelude::event_target;
+use leptos::web_sys::{Event, HtmlInputElement};
 
 fn main() {
-    event_target::<u32>();
+    let ev = Event::new("input").unwrap();
 
-    let _a: Option<&str> = prelude::event_target();
+    event_target::<HtmlInputElement>(&ev);
 
-    leptos::prelude::event_target::<String>();
+    let _a: HtmlInputElement = prelude::event_target(&ev);
 
-    let _b: Option<Option<u8>> = ::leptos::prelude::event_target();
+    leptos::prelude::event_target::<leptos::web_sys::HtmlInputElement>(&ev);
+
+    let _b: HtmlInputElement = ::leptos::prelude::event_target(&Event::new("input").unwrap());
+
+    // Functions with the same name defined elsewhere don't trigger the lint
+    let _c: Option<u8> = helpers::event_target(&ev);
+}
+
+mod helpers {
+    pub fn event_target<T>(_event: &leptos::web_sys::Event) -> Option<T> {
+        None
+    }
 }
@@ -0,0 +1,11 @@
+use leptos::prelude::event_target;
+use leptos::web_sys::{Event, HtmlInputElement, MouseEvent};
+
+fn main() {
+    // Events of `allowed_event_types` don't trigger the lint
+    let click = MouseEvent::new("click").unwrap();
+    let _input: HtmlInputElement = event_target(&click);
+
+    let ev = Event::new("input").unwrap();
+    let _input: HtmlInputElement = event_target(&ev);
+}
@@ -0,0 +1,66 @@
+use leptos::ev;
+use leptos::html::input;
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::{
+    CustomEvent, Event, FetchEvent, FocusEvent, HtmlInputElement, KeyboardEvent,
+};
+
+// Events dispatched by the browser to elements have a target
+fn focused() -> FocusEvent {
+    unimplemented!()
+}
+
+fn on_focus() -> HtmlInputElement {
+    let ev = focused();
+    event_target(&ev)
+}
+
+// ...but functions can be called with events of any origin
+fn on_key(ev: &KeyboardEvent) -> HtmlInputElement {
+    event_target(ev)
+}
+
+// ...and events created by code don't have one until they're dispatched
+fn simulate_key() -> Result<HtmlInputElement, JsValue> {
+    let ev = KeyboardEvent::new("keydown")?;
+    Ok(event_target(&ev))
+}
+
+fn on_fetch(ev: &FetchEvent) -> HtmlInputElement {
+    event_target(ev)
+}
+
+fn on_custom(ev: &CustomEvent) -> HtmlInputElement {
+    event_target(ev)
+}
+
+fn on_any(ev: &Event) -> HtmlInputElement {
+    event_target(ev)
+}
+
+fn main() {
+    // Events received by event listeners have a target
+    let _input = input().on(ev::input, |ev| {
+        let _input = event_target::<HtmlInputElement>(&ev);
+    });
+
+    let _input = input().on(ev::change, move |ev: Event| {
+        let _input: HtmlInputElement = event_target(&ev);
+    });
+
+    let _view = view! {
+        <input on:input={|ev| {
+            let _input = event_target::<HtmlInputElement>(&ev);
+        }} />
+    };
+
+    let _listener = window_event_listener(ev::change, |ev| {
+        let _input = event_target::<HtmlInputElement>(&ev);
+    });
+
+    let custom = CustomEvent::new("refresh").unwrap();
+    let _input = event_target::<HtmlInputElement>(&custom);
+
+    let _ = (on_focus, on_key, simulate_key, on_fetch, on_custom, on_any);
+}
@@ -0,0 +1,185 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_errors;
+extern crate rustc_hir;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_indent;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{enclosing_call, expr_ty_is_any_of, paths, sugg};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use serde::Deserialize;
+
+dylint_linting::impl_late_lint! {
+    /// ### What it does
+    ///
+    /// Warn about usages of `event_target_checked`. The purpose of this lint is
+    /// to make users know that the function may panic under certain circumstances.
+    ///
+    /// Events are only dispatched in the browser, so the lint is skipped when
+    /// compiling for the server with the `ssr` feature.
+    ///
+    /// Events received by event listeners are reported too: they have a target,
+    /// but it's not sure to be an `HtmlInputElement`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The function `leptos::prelude::event_target_checked` will panic at run-time
+    /// if the event doesn't have a target (eg. `web_sys::FetchEvent`) or is
+    /// manually created with `web_sys::Event::new` or `web_sys::MouseEvent::new`.
+    /// The target is also assumed to be an `HtmlInputElement` without checking
+    /// it, so the function panics as well when the target is another element.
+    ///
+    /// To reproduce:
+    ///
+    /// ```rust,ignore
+    /// let event = web_sys::Event::new("change").unwrap();
+    /// let checked = leptos::prelude::event_target_checked(&event);
+    /// ```
+    ///
+    /// The underlying problem is that the MDN APIs don't guarantee at type level
+    /// the element returned by `target`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let checked = leptos::prelude::event_target_checked(&event);
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// let checked = event.target().and_then(|t| {
+    ///     t.dyn_into::<web_sys::HtmlInputElement>().ok().map(|el| el.checked())
+    /// });
+    /// ```
+    ///
+    /// The lint suggests this replacement, leaving to you the handling of the
+    /// `None` case.
+    ///
+    /// Or allow the lint if you're sure that the event has a target and the target
+    /// is an `HtmlInputElement`:
+    ///
+    /// ```rust,ignore
+    /// #[allow(leptos_event_target_checked)]
+    /// let checked = leptos::prelude::event_target_checked(&event);
+    /// ```
+    ///
+    /// ### Configuration
+    ///
+    /// - `allowed_event_types`: types of events known to always target an
+    ///   `HtmlInputElement`, like the `InputEvent`s of an app that only listens to
+    ///   them on checkboxes and radio buttons. Calls passing these events are
+    ///   allowed. Default: `[]`.
+    ///
+    /// ```toml
+    /// [leptos_event_target_checked]
+    /// allowed_event_types = ["web_sys::InputEvent"]
+    /// ```
+    pub LEPTOS_EVENT_TARGET_CHECKED,
+    Warn,
+    "Warn about usages of `leptos::prelude::event_target_checked`",
+    LeptosEventTargetChecked::new()
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed_event_types: Vec<String>,
+}
+
+pub struct LeptosEventTargetChecked {
+    config: Config,
+}
+
+impl LeptosEventTargetChecked {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+        }
+    }
+}
+
+impl Default for LeptosEventTargetChecked {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosEventTargetChecked {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        // Events are only dispatched in the browser
+        if !paths::EVENT_TARGET_CHECKED.matches(cx, def_id) || !should_lint(cx.tcx, Side::Client) {
+            return;
+        }
+
+        let call = enclosing_call(cx, expr);
+        if let Some((_, [event])) = call
+            && expr_ty_is_any_of(cx, event, &self.config.allowed_event_types)
+        {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            LEPTOS_EVENT_TARGET_CHECKED,
+            expr.span,
+            "use of `leptos::prelude::event_target_checked`",
+            |diag| {
+                diag.help(
+                    "this function panics at run-time if the event doesn't have a target \
+                     or the target isn't an `HtmlInputElement`",
+                );
+                if let Some((call, [event])) = call {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let event = sugg::receiver_snippet(cx, event, &mut applicability);
+                    let indent = snippet_indent(cx, call.span).unwrap_or_default();
+                    let sugg = [
+                        format!("{event}.target().and_then(|t| {{"),
+                        "    t.dyn_into::<leptos::web_sys::HtmlInputElement>().ok().map(|el| el.checked())"
+                            .to_string(),
+                        "})".to_string(),
+                    ]
+                    .join(&format!("\n{indent}"));
+                    diag.span_suggestion(
+                        call.span,
+                        "handle a missing target or a target that isn't an `HtmlInputElement`",
+                        sugg,
+                        applicability,
+                    );
+                }
+                diag.help(
+                    "for further information visit \
+                     https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_event_target_checked#readme",
+                );
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME"))
+            .dylint_toml(
+                r#"
+                [leptos_event_target_checked]
+                allowed_event_types = ["leptos::web_sys::InputEvent"]
+                "#,
+            )
+            .run();
+    }
+}
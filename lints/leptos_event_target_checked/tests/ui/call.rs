@@ -0,0 +1,24 @@
+use leptos::prelude;
+use leptos::prelude::event_target_checked;
+use leptos::web_sys::Event;
+
+fn main() {
+    let ev = Event::new("change").unwrap();
+
+    let _checked = event_target_checked(&ev);
+
+    let _a: bool = prelude::event_target_checked(&ev);
+
+    let _d = leptos::prelude::event_target_checked(&ev);
+
+    let _b = ::leptos::prelude::event_target_checked(&Event::new("change").unwrap());
+
+    // Functions with the same name defined elsewhere don't trigger the lint
+    let _c: Option<bool> = helpers::event_target_checked(&ev);
+}
+
+mod helpers {
+    pub fn event_target_checked<T>(_event: &T) -> Option<bool> {
+        None
+    }
+}
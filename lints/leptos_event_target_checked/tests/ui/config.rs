@@ -0,0 +1,11 @@
+use leptos::prelude::event_target_checked;
+use leptos::web_sys::{Event, InputEvent};
+
+fn main() {
+    // Events of `allowed_event_types` don't trigger the lint
+    let input = InputEvent::new("input").unwrap();
+    let _checked = event_target_checked(&input);
+
+    let ev = Event::new("change").unwrap();
+    let _checked = event_target_checked(&ev);
+}
@@ -0,0 +1,26 @@
+use leptos::ev;
+use leptos::html::input;
+use leptos::prelude::*;
+use leptos::web_sys::{Event, KeyboardEvent};
+
+// Events of any origin can have a target that isn't an `HtmlInputElement`
+fn on_key(ev: &KeyboardEvent) -> bool {
+    event_target_checked(ev)
+}
+
+fn main() {
+    // ...even the ones received by event listeners
+    let _input = input().on(ev::change, |ev| {
+        let _checked = event_target_checked(&ev);
+    });
+
+    let _listener = window_event_listener(ev::keydown, |ev| {
+        let _checked = event_target_checked(&ev);
+    });
+
+    // Events created by code don't have a target until they're dispatched
+    let ev = Event::new("change").unwrap();
+    let _checked = event_target_checked(&ev);
+
+    let _ = on_key;
+}
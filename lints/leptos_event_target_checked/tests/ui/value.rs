@@ -0,0 +1,14 @@
+use leptos::prelude::event_target_checked;
+use leptos::web_sys::Event;
+
+fn main() {
+    let events = [Event::new("change").unwrap(), Event::new("change").unwrap()];
+
+    let _checked: Vec<bool> = events.iter().map(event_target_checked).collect();
+
+    let read: fn(&Event) -> bool = leptos::prelude::event_target_checked;
+    let _checked = read(&events[0]);
+
+    let callback = leptos::prelude::event_target_checked;
+    let _checked = callback(&events[1]);
+}
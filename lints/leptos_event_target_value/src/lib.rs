@@ -1,18 +1,30 @@
 #![feature(rustc_private)]
 #![warn(unused_extern_crates)]
 
+extern crate rustc_errors;
 extern crate rustc_hir;
 
-use clippy_utils::diagnostics::span_lint_and_help;
-use rustc_hir::{Expr, ExprKind, QPath};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_indent;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{enclosing_call, expr_ty_is_any_of, paths, sugg};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
+use serde::Deserialize;
 
-dylint_linting::declare_late_lint! {
+dylint_linting::impl_late_lint! {
     /// ### What it does
     ///
     /// Warn about usages of `event_target_value`. The purpose of this lint is
     /// to make users know that the function may panic under certain circumstances.
     ///
+    /// Events are only dispatched in the browser, so the lint is skipped when
+    /// compiling for the server with the `ssr` feature.
+    ///
+    /// Events received by event listeners are reported too: they have a target,
+    /// but it's not sure to be an element with a `value` property.
+    ///
     /// ### Why is this bad?
     ///
     /// The function `leptos::prelude::event_target_value` will panic at run-time
@@ -47,21 +59,17 @@ dylint_linting::declare_late_lint! {
     /// Use instead:
     ///
     /// ```rust,ignore
-    /// let value = match event.target() {
-    ///     Some(t) => match t.dyn_into::<web_sys::HtmlInputElement>() {
-    ///         Ok(input) => Some(input.value()),
-    ///         Err(_) => {
-    ///             // handle the case where the target doesn't have a value
-    ///            todo!()
-    ///         },
-    ///     },
-    ///     None => {
-    ///        // handle the case where there is no target
-    ///        todo!()
-    ///     },
-    /// };
+    /// let value = event.target().and_then(|t| {
+    ///     t.dyn_ref::<web_sys::HtmlInputElement>()
+    ///         .map(|el| el.value())
+    ///         .or_else(|| t.dyn_ref::<web_sys::HtmlTextAreaElement>().map(|el| el.value()))
+    ///         .or_else(|| t.dyn_ref::<web_sys::HtmlSelectElement>().map(|el| el.value()))
+    /// });
     /// ```
     ///
+    /// The lint suggests this replacement, leaving to you the handling of the
+    /// `None` case.
+    ///
     /// Or allow the lint if you're sure that the event has a target and the target
     /// has a `value()` method:
     ///
@@ -69,18 +77,52 @@ dylint_linting::declare_late_lint! {
     /// #[allow(leptos_event_target_value)]
     /// let value = leptos::prelude::event_target_value::<web_sys::Document>(&event);
     /// ```
+    ///
+    /// ### Configuration
+    ///
+    /// - `allowed_event_types`: types of events known to always target an element
+    ///   with a `value` property, like the `InputEvent`s of an app that only listens
+    ///   to them on inputs, text areas and selects. Calls passing these events are
+    ///   allowed. Default: `[]`.
+    ///
+    /// ```toml
+    /// [leptos_event_target_value]
+    /// allowed_event_types = ["web_sys::InputEvent"]
+    /// ```
     pub LEPTOS_EVENT_TARGET_VALUE,
     Warn,
-    "Warn about usages of `leptos::prelude::event_target_value`"
+    "Warn about usages of `leptos::prelude::event_target_value`",
+    LeptosEventTargetValue::new()
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed_event_types: Vec<String>,
+}
+
+pub struct LeptosEventTargetValue {
+    config: Config,
+}
+
+impl LeptosEventTargetValue {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+        }
+    }
+}
+
+impl Default for LeptosEventTargetValue {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'tcx> LateLintPass<'tcx> for LeptosEventTargetValue {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
-        let ExprKind::Call(func, _) = expr.kind else {
-            return;
-        };
-
-        let ExprKind::Path(ref qpath) = func.kind else {
+        let ExprKind::Path(ref qpath) = expr.kind else {
             return;
         };
 
@@ -88,23 +130,55 @@ impl<'tcx> LateLintPass<'tcx> for LeptosEventTargetValue {
             return;
         };
 
-        if cx.tcx.item_name(def_id).as_str() != "event_target_value" {
+        // Events are only dispatched in the browser
+        if !paths::EVENT_TARGET_VALUE.matches(cx, def_id) || !should_lint(cx.tcx, Side::Client) {
             return;
         }
 
-        let QPath::Resolved(_, path) = qpath else {
+        let call = enclosing_call(cx, expr);
+        if let Some((_, [event])) = call
+            && expr_ty_is_any_of(cx, event, &self.config.allowed_event_types)
+        {
             return;
-        };
+        }
 
-        span_lint_and_help(
+        span_lint_and_then(
             cx,
             LEPTOS_EVENT_TARGET_VALUE,
-            path.span,
+            expr.span,
             "use of `leptos::prelude::event_target_value`",
-            None,
-            "this function can panic at run-time if the target doesn't have an event. \
-                  For further information visit \
-                  https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_event_target_value#readme",
+            |diag| {
+                diag.help(
+                    "this function panics at run-time if the event doesn't have a target \
+                     or the target doesn't have a `value` property",
+                );
+                if let Some((call, [event])) = call {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let event = sugg::receiver_snippet(cx, event, &mut applicability);
+                    let indent = snippet_indent(cx, call.span).unwrap_or_default();
+                    let value_of = |element: &str| {
+                        format!("t.dyn_ref::<leptos::web_sys::{element}>().map(|el| el.value())")
+                    };
+                    let sugg = [
+                        format!("{event}.target().and_then(|t| {{"),
+                        format!("    {}", value_of("HtmlInputElement")),
+                        format!("        .or_else(|| {})", value_of("HtmlTextAreaElement")),
+                        format!("        .or_else(|| {})", value_of("HtmlSelectElement")),
+                        "})".to_string(),
+                    ]
+                    .join(&format!("\n{indent}"));
+                    diag.span_suggestion(
+                        call.span,
+                        "handle a missing target or `value` property",
+                        sugg,
+                        applicability,
+                    );
+                }
+                diag.help(
+                    "for further information visit \
+                     https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_event_target_value#readme",
+                );
+            },
         );
     }
 }
@@ -113,6 +187,13 @@ impl<'tcx> LateLintPass<'tcx> for LeptosEventTargetValue {
 mod tests {
     #[test]
     fn ui_examples() {
-        dylint_testing::ui_test_examples(env!("CARGO_PKG_NAME"));
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME"))
+            .dylint_toml(
+                r#"
+                [leptos_event_target_value]
+                allowed_event_types = ["leptos::web_sys::InputEvent"]
+                "#,
+            )
+            .run();
     }
 }
@@ -1,5 +0,0 @@
-pub mod prelude {
-    pub fn event_target_value<T>() -> Option<T> {
-        None
-    }
-}
@@ -1,12 +1,24 @@
 use leptos::prelude;
 use leptos::prelude::event_target_value;
+use leptos::web_sys::Event;
 
 fn main() {
-    event_target_value::<u32>();
+    let ev = Event::new("input").unwrap();
 
-    let _a: Option<&str> = prelude::event_target_value();
+    event_target_value(&ev);
 
-    leptos::prelude::event_target_value::<String>();
+    let _a: String = prelude::event_target_value(&ev);
 
-    let _b: Option<Option<u8>> = ::leptos::prelude::event_target_value();
+    leptos::prelude::event_target_value::<Event>(&ev);
+
+    let _b = ::leptos::prelude::event_target_value(&Event::new("input").unwrap());
+
+    // Functions with the same name defined elsewhere don't trigger the lint
+    let _c: Option<String> = helpers::event_target_value(&ev);
+}
+
+mod helpers {
+    pub fn event_target_value<T>(_event: &T) -> Option<String> {
+        None
+    }
 }
@@ -0,0 +1,11 @@
+use leptos::prelude::event_target_value;
+use leptos::web_sys::{Event, InputEvent};
+
+fn main() {
+    // Events of `allowed_event_types` don't trigger the lint
+    let input = InputEvent::new("input").unwrap();
+    let _value = event_target_value(&input);
+
+    let ev = Event::new("input").unwrap();
+    let _value = event_target_value(&ev);
+}
@@ -0,0 +1,22 @@
+use leptos::ev;
+use leptos::html::{input, textarea};
+use leptos::prelude::*;
+use leptos::web_sys::Event;
+
+// Events of any origin can have a target without a `value` property
+fn on_any(ev: &Event) -> String {
+    event_target_value(ev)
+}
+
+fn main() {
+    // ...even the ones received by event listeners
+    let _input = input().on(ev::input, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    let _textarea = textarea().on(ev::change, move |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    let _ = on_any;
+}
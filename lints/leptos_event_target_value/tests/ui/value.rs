@@ -0,0 +1,14 @@
+use leptos::prelude::event_target_value;
+use leptos::web_sys::Event;
+
+fn main() {
+    let events = [Event::new("input").unwrap(), Event::new("change").unwrap()];
+
+    let _values: Vec<String> = events.iter().map(event_target_value).collect();
+
+    let read: fn(&Event) -> String = leptos::prelude::event_target_value;
+    let _value = read(&events[0]);
+
+    let callback = leptos::prelude::event_target_value::<Event>;
+    let _value = callback(&events[1]);
+}
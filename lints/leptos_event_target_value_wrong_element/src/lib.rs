@@ -0,0 +1,247 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_hir;
+extern crate rustc_middle;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_parent_expr;
+use leptos_lints_utils::event::on_event_call;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{enclosing_call, paths};
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for calls to `leptos::prelude::event_target_value` in event
+    /// listeners attached with `on:` attributes of `view!` or `.on()` to elements
+    /// without a `value` property, like `<div on:click=...>` or
+    /// `<span on:input=...>`.
+    ///
+    /// The elements with a `value` property are `<input>`, `<textarea>`,
+    /// `<select>`, `<option>`, `<button>`, `<data>`, `<output>` and `<param>`.
+    ///
+    /// Most events bubble, so their target can be a descendant of the element
+    /// where the listener is attached, like the `<input>` of a
+    /// `<form on:input=...>`. Listeners of these events are only checked when the
+    /// element has no child elements. Children that render text, like strings,
+    /// numbers, or signals and closures of them, are not elements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `event_target_value` reads the `value` property of the target of the event.
+    /// When the target doesn't have it, the call fails at run-time with:
+    ///
+    /// ```ignore
+    /// TypeError: Cannot read properties of undefined (reading 'length')
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// view! {
+    ///     <div on:click=move |ev| set_name.set(event_target_value(&ev))>
+    ///         {name}
+    ///     </div>
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// view! {
+    ///     <input on:input=move |ev| set_name.set(event_target_value(&ev)) prop:value=name />
+    /// }
+    /// ```
+    pub LEPTOS_EVENT_TARGET_VALUE_WRONG_ELEMENT,
+    Deny,
+    "Checks for `leptos::prelude::event_target_value` in listeners of elements without a `value` property"
+}
+
+/// Elements with a `value` property, by the names of their types in `tachys`.
+const VALUE_ELEMENTS: &[&str] = &[
+    "Button", "Data", "Input", "Option", "Output", "Param", "Select", "Textarea",
+];
+
+/// Events that don't bubble, so their target is always the element where the
+/// listener is attached.
+const NON_BUBBLING_EVENTS: &[&str] = &[
+    "blur",
+    "focus",
+    "invalid",
+    "load",
+    "mouseenter",
+    "mouseleave",
+    "pointerenter",
+    "pointerleave",
+    "toggle",
+];
+
+impl<'tcx> LateLintPass<'tcx> for LeptosEventTargetValueWrongElement {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        // Events are only dispatched in the browser
+        if !paths::EVENT_TARGET_VALUE.matches(cx, def_id) || !should_lint(cx.tcx, Side::Client) {
+            return;
+        }
+
+        let Some((call, [event])) = enclosing_call(cx, expr) else {
+            return;
+        };
+        let Some(on_call) = on_event_call(cx, event) else {
+            return;
+        };
+        let ExprKind::MethodCall(_, element, [descriptor, _], _) = on_call.kind else {
+            return;
+        };
+        let Some(element_name) = element_name(cx, element) else {
+            return;
+        };
+        if VALUE_ELEMENTS.contains(&element_name.as_str()) {
+            return;
+        }
+        let bubbles = match *cx.typeck_results().expr_ty(descriptor).kind() {
+            ty::Adt(adt, _) => !NON_BUBBLING_EVENTS.contains(&cx.tcx.item_name(adt.did()).as_str()),
+            _ => true,
+        };
+        if bubbles && has_child_elements(cx, on_call) {
+            return;
+        }
+
+        // The event of an `on:` attribute of `view!`, like `click`
+        let descriptor_span = match descriptor.kind {
+            ExprKind::Path(QPath::Resolved(None, path)) if descriptor.span.from_expansion() => path
+                .segments
+                .last()
+                .map_or(descriptor.span, |segment| segment.ident.span),
+            _ => descriptor.span,
+        };
+        let tag = element_name.to_lowercase();
+        span_lint_and_then(
+            cx,
+            LEPTOS_EVENT_TARGET_VALUE_WRONG_ELEMENT,
+            call.span,
+            format!(
+                "the target of this event is a `<{tag}>` element, which has no `value` property"
+            ),
+            |diag| {
+                diag.span_note(
+                    descriptor_span,
+                    format!("the event listener is attached to a `<{tag}>` element here"),
+                );
+                diag.help(
+                    "attach the listener to an element with a `value` property, like `<input>`, \
+                     `<textarea>` or `<select>`",
+                );
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_event_target_value_wrong_element#readme");
+            },
+        );
+    }
+}
+
+/// Name of the type of an HTML element in `tachys`, like `Div`. Custom elements
+/// are not known.
+fn element_name(cx: &LateContext<'_>, element: &Expr<'_>) -> Option<String> {
+    let ty::Adt(adt, args) = *cx.typeck_results().expr_ty(element).kind() else {
+        return None;
+    };
+    if !paths::HTML_ELEMENT.matches(cx, adt.did()) {
+        return None;
+    }
+    let ty::Adt(kind, _) = *args.types().next()?.kind() else {
+        return None;
+    };
+    let name = cx.tcx.item_name(kind.did());
+    (name.as_str() != "Custom").then(|| name.to_string())
+}
+
+/// Check if the element of a call of `.on()` may have child elements, added with
+/// `.child()` before or after it.
+fn has_child_elements(cx: &LateContext<'_>, on_call: &Expr<'_>) -> bool {
+    let is_element_child = |method_call: &Expr<'_>| {
+        cx.typeck_results()
+            .type_dependent_def_id(method_call.hir_id)
+            .is_some_and(|def_id| paths::ELEMENT_CHILD.matches(cx, def_id))
+    };
+    let has_element = |args: &[Expr<'_>]| {
+        args.iter()
+            .any(|arg| may_be_element(cx, cx.typeck_results().expr_ty(arg)))
+    };
+
+    // `div().child(..).on(..)`
+    let mut receiver = on_call;
+    while let ExprKind::MethodCall(_, inner, args, _) = receiver.kind {
+        if is_element_child(receiver) && has_element(args) {
+            return true;
+        }
+        receiver = inner;
+    }
+
+    // `div().on(..).child(..)`
+    let mut current = on_call;
+    while let Some(parent) = get_parent_expr(cx, current)
+        && let ExprKind::MethodCall(_, inner, args, _) = parent.kind
+        && inner.hir_id == current.hir_id
+    {
+        if is_element_child(parent) && has_element(args) {
+            return true;
+        }
+        current = parent;
+    }
+    false
+}
+
+/// Check if a child may render elements. Text, like `"Click"` or `count`, and
+/// the signals and closures whose values are text don't, nor do other types that
+/// aren't views, like the values of attributes.
+fn may_be_element<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    match *ty.peel_refs().kind() {
+        ty::Adt(adt, _) if paths::HTML_ELEMENT.matches(cx, adt.did()) => true,
+        ty::Adt(adt, _) if paths::VIEW_TYPES.matches(cx, adt.did()) => true,
+        // Signals, memos, and wrappers like `Option` or `Vec`, are rendered as
+        // their values
+        ty::Adt(_, args) => args.types().any(|ty| may_be_element(cx, ty)),
+        ty::Tuple(tys) => tys.iter().any(|ty| may_be_element(cx, ty)),
+        ty::Array(ty, _) | ty::Slice(ty) => may_be_element(cx, ty),
+        // Closures are rendered as their outputs, like `move || count.get()`
+        ty::Closure(_, args) => may_be_element(cx, args.as_closure().sig().output().skip_binder()),
+        ty::FnDef(def_id, _) => may_be_element(
+            cx,
+            cx.tcx
+                .fn_sig(def_id)
+                .instantiate_identity()
+                .output()
+                .skip_binder(),
+        ),
+        ty::FnPtr(sig_tys, _) => may_be_element(cx, sig_tys.skip_binder().output()),
+        // `Box<dyn Fn() -> AnyView>`, like `Children`
+        ty::Dynamic(predicates, ..) => predicates.projection_bounds().any(|projection| {
+            projection
+                .skip_binder()
+                .term
+                .as_type()
+                .is_some_and(|ty| may_be_element(cx, ty))
+        }),
+        // The views returned by functions as `impl IntoView`, like components
+        ty::Alias(ty::Opaque, _) | ty::Param(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
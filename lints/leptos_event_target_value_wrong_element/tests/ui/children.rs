@@ -0,0 +1,55 @@
+use leptos::ev;
+use leptos::html::{div, form, input, select};
+use leptos::prelude::*;
+
+fn main() {
+    // Events bubble from the child elements, which may have a `value` property
+    let _form = form().child(input()).on(ev::input, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    let _div = div()
+        .on(ev::change, |ev| {
+            let _value = event_target_value(&ev);
+        })
+        .child(select());
+
+    // Text is not an element, so the event can only target the `<div>`
+    let _div = div().child("Click me").on(ev::click, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    let _div = div()
+        .on(ev::click, |ev| {
+            let _value = event_target_value(&ev);
+        })
+        .child(("Clicked ", 3, " times"));
+
+    let _view = view! {
+        <form on:input={|ev| {
+            let _value = event_target_value(&ev);
+        }}>
+            <input />
+        </form>
+    };
+
+    let (name, set_name) = signal(String::new());
+    let _view = view! {
+        <div on:click={move |ev| set_name.set(event_target_value(&ev))}>
+            {move || view! { <input /> }}
+        </div>
+    };
+
+    // Signals and closures of text are not elements either
+    let _view = view! {
+        <div on:click={move |ev| set_name.set(event_target_value(&ev))}>
+            {name}
+        </div>
+    };
+
+    let _view = view! {
+        <div on:click={move |ev| set_name.set(event_target_value(&ev))}>
+            "Hello, " {move || name.get()} "!"
+        </div>
+    };
+}
@@ -0,0 +1,49 @@
+use leptos::ev;
+use leptos::html::{button, div, input, select, span, textarea};
+use leptos::prelude::*;
+
+fn main() {
+    // Elements without a `value` property
+    let _div = div().on(ev::click, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    let _span = span().on(ev::input, move |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    // Events that don't bubble always target the element of the listener
+    let _div = div().child(input()).on(ev::focus, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    // Elements with a `value` property
+    let _input = input().on(ev::input, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    let _select = select().on(ev::change, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    let _textarea = textarea().on(ev::blur, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    let _button = button().on(ev::click, |ev| {
+        let _value = event_target_value(&ev);
+    });
+
+    // Listeners attached with `on:` attributes of `view!`
+    let _view = view! {
+        <div on:click={|ev| {
+            let _value = event_target_value(&ev);
+        }}>"Click me"</div>
+    };
+
+    let _view = view! {
+        <input on:input={|ev| {
+            let _value = event_target_value(&ev);
+        }} />
+    };
+}
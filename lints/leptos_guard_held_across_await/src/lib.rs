@@ -0,0 +1,130 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_hir;
+extern crate rustc_middle;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::paths;
+use rustc_hir::{Closure, ClosureKind, CoroutineDesugaring, CoroutineKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::mir::CoroutineLayout;
+use rustc_middle::ty;
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for the guards returned by `.read()`, `.write()` and
+    /// `.write_untracked()` of signals, and by `.read_value()` and `.write_value()`
+    /// of `StoredValue`, that are alive across an `.await` in an async function or
+    /// block. The guards of stores are checked too: stores return the guards of
+    /// signals, and their type-erased and keyed fields return their own,
+    /// `StoreFieldReader`, `StoreFieldWriter` and `KeyedSubfieldWriteGuard`.
+    ///
+    /// It works like the `await_holding_refcell_ref` lint of Clippy, for these
+    /// guards and the `ReadGuard`, `WriteGuard` and `UntrackedWriteGuard` types of
+    /// signals. Like
+    /// it, guards dropped with `drop()` before the `.await` are still reported, so
+    /// they must be dropped by ending their scope instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A guard holds a borrow of the value of the signal until it's dropped. Other
+    /// tasks that run while the future is waiting can't borrow it, so they panic
+    /// with `already borrowed` in the browser, or deadlock on the server, where
+    /// the value is behind a lock.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// spawn_local(async move {
+    ///     let mut todos = todos.write();
+    ///     let todo = fetch_todo(id).await;
+    ///     todos.push(todo);
+    /// });
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// spawn_local(async move {
+    ///     let todo = fetch_todo(id).await;
+    ///     todos.write().push(todo);
+    /// });
+    /// ```
+    pub LEPTOS_GUARD_HELD_ACROSS_AWAIT,
+    Warn,
+    "Checks for guards of signals held across await points"
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosGuardHeldAcrossAwait {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Closure(Closure {
+            kind: ClosureKind::Coroutine(CoroutineKind::Desugared(CoroutineDesugaring::Async, _)),
+            def_id,
+            ..
+        }) = expr.kind
+        else {
+            return;
+        };
+
+        if !should_lint(cx.tcx, Side::Both) {
+            return;
+        }
+
+        if let Some(layout) = cx.tcx.mir_coroutine_witnesses(*def_id) {
+            check_guards(cx, layout);
+        }
+    }
+}
+
+/// Report the guards saved by a coroutine across its await points.
+fn check_guards(cx: &LateContext<'_>, layout: &CoroutineLayout<'_>) {
+    for (index, saved) in layout.field_tys.iter_enumerated() {
+        let ty::Adt(adt, _) = *saved.ty.kind() else {
+            continue;
+        };
+        if !paths::SIGNAL_GUARD_TYPES.matches(cx, adt.did()) {
+            continue;
+        }
+
+        // Each await point is a variant of the coroutine, with the values saved
+        // across it as fields
+        let await_points = layout
+            .variant_source_info
+            .iter_enumerated()
+            .filter(|(variant, _)| layout.variant_fields[*variant].raw.contains(&index))
+            .map(|(_, source_info)| source_info.span)
+            .collect::<Vec<_>>();
+        if await_points.is_empty() {
+            continue;
+        }
+
+        let guard = cx.tcx.item_name(adt.did());
+        span_lint_and_then(
+            cx,
+            LEPTOS_GUARD_HELD_ACROSS_AWAIT,
+            await_points,
+            format!("`{guard}` held across an await point"),
+            |diag| {
+                diag.span_note(
+                    saved.source_info.span,
+                    "the guard is created here, and borrows the value until it's dropped",
+                );
+                diag.help(
+                    "drop the guard before the `.await`, or copy or clone the value out of it",
+                );
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_guard_held_across_await#readme");
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
@@ -0,0 +1,70 @@
+use leptos::prelude::*;
+use leptos::task::{spawn_local, tick};
+use reactive_stores::{ArcField, Store};
+
+async fn load(todos: RwSignal<Vec<u32>>) {
+    let mut todos = todos.write();
+    tick().await;
+    todos.push(1);
+}
+
+async fn total(todos: RwSignal<Vec<u32>>, cache: StoredValue<u32>) -> u32 {
+    let todos = todos.read();
+    let cached = cache.read_value();
+    tick().await;
+    tick().await;
+    todos.iter().sum::<u32>() + *cached
+}
+
+async fn sync(todos: ArcRwSignal<Vec<u32>>) {
+    let mut todos = todos.write();
+    tick().await;
+    todos.clear();
+}
+
+// Fields of stores return their own guards
+async fn count(todos: ArcField<Vec<u32>>) -> usize {
+    let todos = todos.read();
+    tick().await;
+    todos.len()
+}
+
+async fn reset(todos: ArcField<Vec<u32>>) {
+    let mut todos = todos.write();
+    tick().await;
+    todos.clear();
+}
+
+// Guards dropped before the await point
+async fn save(todos: RwSignal<Vec<u32>>) {
+    let count = todos.read().len();
+    {
+        let mut todos = todos.write_untracked();
+        todos.truncate(count / 2);
+    }
+    tick().await;
+}
+
+fn main() {
+    let todos = RwSignal::new(vec![1, 2, 3]);
+    let cache = StoredValue::new(0);
+
+    spawn_local(async move {
+        let mut cache = cache.write_value();
+        load(todos).await;
+        *cache += 1;
+    });
+
+    spawn_local(async move {
+        let _ = total(todos, cache).await;
+        save(todos).await;
+    });
+
+    let shared = ArcRwSignal::new(vec![1, 2, 3]);
+    let field = ArcField::from(Store::new(vec![1, 2, 3]));
+    spawn_local(async move {
+        sync(shared).await;
+        let _ = count(field.clone()).await;
+        reset(field).await;
+    });
+}
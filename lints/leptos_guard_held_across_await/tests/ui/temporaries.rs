@@ -0,0 +1,26 @@
+use leptos::prelude::*;
+use leptos::task::{spawn_local, tick};
+
+async fn fetch_todo(id: u32) -> u32 {
+    tick().await;
+    id
+}
+
+fn main() {
+    let todos = RwSignal::new(Vec::new());
+    let offset = RwSignal::new(0);
+
+    spawn_local(async move {
+        // The guard of `.write()` is created before the argument is awaited
+        todos.write().push(fetch_todo(1).await);
+
+        // Temporaries live until the end of the statement
+        let _id = *offset.read() + fetch_todo(2).await;
+    });
+
+    spawn_local(async move {
+        // Awaiting first
+        let todo = fetch_todo(3).await;
+        todos.write().push(todo);
+    });
+}
@@ -0,0 +1,128 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_errors;
+extern crate rustc_hir;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use leptos_lints_utils::context::{OwnerUse, future_owner_use};
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::sugg::replace_fn_path;
+use leptos_lints_utils::{enclosing_call, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for calls to `leptos::task::spawn_local_scoped` whose futures don't
+    /// need the reactive owner, and recommends using `leptos::task::spawn_local`
+    /// instead. This is the counterpart of [`leptos_unscoped_spawn`].
+    ///
+    /// [`leptos_unscoped_spawn`]: https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_unscoped_spawn#readme
+    ///
+    /// The spawned future is walked, with the local functions and closures that it
+    /// calls, like [`leptos_unscoped_spawn`] does. The lint is only triggered when the
+    /// future provably doesn't read contexts, doesn't create reactive nodes like
+    /// signals, memos, effects or resources, and doesn't use the owner in other ways,
+    /// like providing contexts, registering cleanups or spawning scoped futures.
+    ///
+    /// Functions of Leptos that can't be followed, and aren't known to use the owner,
+    /// are assumed not to use it, like trait methods called on generic types.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A scoped spawn creates a reactive node to keep the owner of the current scope
+    /// available in the future, which costs performance and memory. When the future
+    /// doesn't need the owner, the cost is paid for nothing.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// leptos::task::spawn_local_scoped(async move {
+    ///     let data = fetch_data().await;
+    ///     set_data.set(data);
+    /// });
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// leptos::task::spawn_local(async move {
+    ///     let data = fetch_data().await;
+    ///     set_data.set(data);
+    /// });
+    /// ```
+    ///
+    /// The lint provides a fix that can be applied automatically with
+    /// `cargo dylint --fix` when every call of the future is followed or known not
+    /// to use the owner.
+    pub LEPTOS_NEEDLESS_SCOPED_SPAWN,
+    Warn,
+    "Checks for scoped spawns whose futures don't need the reactive owner"
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosNeedlessScopedSpawn {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        if !paths::SPAWN_LOCAL_SCOPED.matches(cx, def_id) || !should_lint(cx.tcx, Side::Both) {
+            return;
+        }
+
+        // Futures of spawns that are not called can't be followed
+        let Some((_, [future])) = enclosing_call(cx, expr) else {
+            return;
+        };
+        let OwnerUse::Unused { assumed } = future_owner_use(cx, future) else {
+            return;
+        };
+
+        span_lint_and_then(
+            cx,
+            LEPTOS_NEEDLESS_SCOPED_SPAWN,
+            expr.span,
+            "use of `leptos::task::spawn_local_scoped` with a future that doesn't need the reactive owner",
+            |diag| {
+                let edits = match qpath {
+                    QPath::Resolved(None, path) => {
+                        replace_fn_path(cx, path, expr.hir_id, "spawn_local")
+                    }
+                    _ => None,
+                };
+                if let Some(span) = assumed {
+                    diag.span_note(
+                        span,
+                        "this call isn't followed, and is assumed not to need the reactive owner",
+                    );
+                }
+                if let Some(edits) = edits {
+                    let applicability = if assumed.is_some() {
+                        Applicability::MaybeIncorrect
+                    } else {
+                        Applicability::MachineApplicable
+                    };
+                    diag.multipart_suggestion("use an unscoped spawn", edits, applicability);
+                } else {
+                    diag.help("prefer `leptos::task::spawn_local`");
+                }
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_needless_scoped_spawn#readme");
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
@@ -0,0 +1,42 @@
+use leptos::prelude::*;
+use leptos::task;
+use leptos::task::spawn_local_scoped;
+
+async fn fetch_count() -> i32 {
+    task::tick().await;
+    1
+}
+
+fn main() {
+    let (_count, set_count) = signal(0);
+
+    spawn_local_scoped(async move {
+        set_count.set(fetch_count().await);
+    });
+
+    task::spawn_local_scoped(async {
+        // ...
+    });
+
+    leptos::task::spawn_local_scoped(async {
+        // ...
+    });
+
+    ::leptos::task::spawn_local_scoped(fetch_unit());
+
+    renamed_import::spawn();
+}
+
+async fn fetch_unit() {
+    fetch_count().await;
+}
+
+mod renamed_import {
+    use leptos::task::spawn_local_scoped as spawn_scoped;
+
+    pub fn spawn() {
+        spawn_scoped(async {
+            // ...
+        });
+    }
+}
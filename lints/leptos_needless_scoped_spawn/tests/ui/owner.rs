@@ -0,0 +1,89 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use leptos::prelude::*;
+use leptos::router::hooks::use_params_map;
+use leptos::task::{spawn_local, spawn_local_scoped};
+
+#[derive(Clone)]
+struct Theme;
+
+fn create_counter() -> ReadSignal<i32> {
+    let (count, _set_count) = signal(0);
+    count
+}
+
+fn read_count(count: &impl Get<Value = i32>) -> i32 {
+    count.get()
+}
+
+fn main() {
+    // Futures that need the reactive owner don't trigger the lint
+    spawn_local_scoped(async {
+        let _theme = expect_context::<Theme>();
+    });
+
+    spawn_local_scoped(async {
+        let _params = use_params_map();
+    });
+
+    spawn_local_scoped(async {
+        let _count = create_counter();
+    });
+
+    spawn_local_scoped(async {
+        let _doubled = Memo::new(|_| 2);
+    });
+
+    spawn_local_scoped(async {
+        let _stored = StoredValue::new(Theme);
+        let _local = RwSignal::new_local(0);
+        let _shared = ArcRwSignal::new(0);
+        let _trigger = Trigger::new();
+    });
+
+    spawn_local_scoped(async {
+        provide_context(Theme);
+    });
+
+    spawn_local_scoped(async {
+        on_cleanup(|| {});
+    });
+
+    spawn_local_scoped(async {
+        spawn_local_scoped(async {
+            let _theme = expect_context::<Theme>();
+        });
+    });
+
+    // ...nor futures that can't be followed
+    let callback: Box<dyn Fn()> = Box::new(|| {});
+    spawn_local_scoped(async move {
+        callback();
+    });
+
+    let future: Pin<Box<dyn Future<Output = ()>>> = Box::pin(async {});
+    spawn_local_scoped(future);
+
+    spawn_local_scoped(async {
+        let _debounced = leptos_use::signal_debounced(Signal::derive(|| 1), 500.0);
+    });
+
+    // ...nor scoped spawns used as values
+    let spawn: fn(Pin<Box<dyn Future<Output = ()>>>) = spawn_local_scoped;
+    spawn(Box::pin(async {}));
+
+    // Unscoped spawns inside scoped ones don't need the owner
+    spawn_local_scoped(async {
+        spawn_local(async {
+            // ...
+        });
+    });
+
+    // Methods of Leptos traits called on generic types are assumed not to need the
+    // owner, so the fix isn't applied automatically
+    let count = RwSignal::new(0);
+    spawn_local_scoped(async move {
+        let _count = read_count(&count);
+    });
+}
@@ -0,0 +1,166 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_data_structures;
+extern crate rustc_errors;
+extern crate rustc_hir;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::peel_blocks;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{is_from_view, paths};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId, Node};
+use rustc_lint::{LateContext, LateLintPass};
+
+dylint_linting::impl_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for signals read with `.get()`, `.with()`, `.read()` or
+    /// `.get_untracked()` in the children, attribute values and component props of
+    /// `view!`, outside of a closure.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The values of `view!` are evaluated once, when the view is created. A signal
+    /// read there is not tracked by the view, so it shows the first value of the
+    /// signal and is never updated when it changes. Wrapping the read in a closure
+    /// lets the view call it again each time that the signal changes. Reads with
+    /// `.get_untracked()` are never tracked, so they're replaced with `.get()`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// view! {
+    ///     <p>{count.get()}</p>
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// view! {
+    ///     <p>{move || count.get()}</p>
+    /// }
+    /// ```
+    ///
+    /// Component props only accept closures when they're declared with a type
+    /// that can be converted from them, like `#[prop(into)] Signal<T>`, so the
+    /// suggestion may need changes in the component too.
+    pub LEPTOS_NONREACTIVE_READ_IN_VIEW,
+    Warn,
+    "Checks for signals read outside of reactive closures in `view!`",
+    LeptosNonreactiveReadInView::default()
+}
+
+#[derive(Default)]
+pub struct LeptosNonreactiveReadInView {
+    /// Values of `view!` already reported.
+    ///
+    /// A value can read several signals, but wrapping it in a closure once makes
+    /// all of them reactive, so only the first read is reported.
+    reported_values: FxHashSet<HirId>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosNonreactiveReadInView {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::MethodCall(method, ..) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) else {
+            return;
+        };
+
+        // Views are only updated in the browser
+        if !paths::SIGNAL_READS.matches(cx, def_id)
+            || expr.span.from_expansion()
+            || !should_lint(cx.tcx, Side::Client)
+        {
+            return;
+        }
+
+        let Some(value) = eager_view_value(cx, expr) else {
+            return;
+        };
+        if !self.reported_values.insert(value.hir_id) {
+            return;
+        }
+
+        let closure_span = peel_blocks(value).span.source_callsite();
+        span_lint_and_then(
+            cx,
+            LEPTOS_NONREACTIVE_READ_IN_VIEW,
+            expr.span,
+            format!(
+                "signal read with `.{}()` when the view is created",
+                method.ident
+            ),
+            |diag| {
+                diag.note(
+                    "the view shows the value that it has now and isn't updated when it changes",
+                );
+                // Untracked reads are never reactive, even in a closure
+                if let Some(tracked) = method.ident.as_str().strip_suffix("_untracked") {
+                    diag.multipart_suggestion_verbose(
+                        format!(
+                            "read the signal with `.{tracked}()` in a closure to make it reactive"
+                        ),
+                        vec![
+                            (closure_span.shrink_to_lo(), "move || ".to_string()),
+                            (method.ident.span, tracked.to_string()),
+                        ],
+                        Applicability::MaybeIncorrect,
+                    );
+                } else {
+                    diag.span_suggestion_verbose(
+                        closure_span.shrink_to_lo(),
+                        "read the signal in a closure to make it reactive",
+                        "move || ",
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_nonreactive_read_in_view#readme");
+            },
+        );
+    }
+}
+
+/// Value of `view!`, like a child or an attribute value, that evaluates a read
+/// when the view is created.
+///
+/// The parents of the read are walked up to the code generated by `view!`. Reads
+/// inside of closures, which the view can call again, or inside of items are not
+/// evaluated with the view.
+fn eager_view_value<'tcx>(
+    cx: &LateContext<'tcx>,
+    read: &'tcx Expr<'tcx>,
+) -> Option<&'tcx Expr<'tcx>> {
+    let mut value = read;
+    for (_, node) in cx.tcx.hir_parent_iter(read.hir_id) {
+        match node {
+            Node::Expr(parent) if is_from_view(cx, parent.span) => {
+                return (!matches!(parent.kind, ExprKind::Closure(_))).then_some(value);
+            }
+            Node::Expr(Expr {
+                kind: ExprKind::Closure(_),
+                ..
+            })
+            | Node::Item(_)
+            | Node::ImplItem(_)
+            | Node::TraitItem(_) => return None,
+            Node::Expr(parent) => value = parent,
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
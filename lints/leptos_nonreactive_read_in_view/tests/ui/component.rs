@@ -0,0 +1,48 @@
+#![allow(non_snake_case)]
+
+use leptos::html::{HtmlElement, P};
+use leptos::prelude::*;
+
+// What `#[component]` generates for `fn Counter(count: i32, label: &'static str)`
+struct CounterProps;
+
+struct CounterPropsBuilder(CounterProps);
+
+impl CounterPropsBuilder {
+    fn count<V>(self, _count: V) -> Self {
+        self
+    }
+
+    fn label<V>(self, _label: V) -> Self {
+        self
+    }
+
+    fn build(self) -> CounterProps {
+        self.0
+    }
+}
+
+impl leptos::component::Props for CounterProps {
+    type Builder = CounterPropsBuilder;
+
+    fn builder() -> Self::Builder {
+        CounterPropsBuilder(CounterProps)
+    }
+}
+
+fn Counter(_props: CounterProps) -> HtmlElement<P> {
+    view! { <p>"Counter"</p> }
+}
+
+fn main() {
+    let count = RwSignal::new(0);
+    let label = Memo::new(|_| "Clicks");
+
+    // Prop values
+    let _view = view! { <Counter count={count.get()} label="Clicks" /> };
+
+    let _view = view! { <Counter count=count label={label.get()} /> };
+
+    // Reads in closures are reactive
+    let _view = view! { <Counter count={move || count.get()} label="Clicks" /> };
+}
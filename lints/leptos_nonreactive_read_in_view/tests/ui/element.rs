@@ -0,0 +1,37 @@
+use leptos::prelude::*;
+
+fn main() {
+    let (count, set_count) = signal(0);
+    let name = RwSignal::new(String::new());
+
+    // Children
+    let _view = view! { <p>{count.get()}</p> };
+
+    let _view = view! { <p>"Double: " {count.get() * 2}</p> };
+
+    let _view = view! {
+        <div>
+            <span>{name.with(String::len)}</span>
+            <span>{format!("{} characters", name.read().len())}</span>
+        </div>
+    };
+
+    // Attribute values
+    let _view = view! { <input value={name.get_untracked()} /> };
+
+    let _view = view! { <p class={if count.get() > 5 { "big" } else { "small" }}>"Count"</p> };
+
+    // Several reads of the same value are reported once
+    let _view = view! { <p>{count.get() + count.get()}</p> };
+
+    // Reads in closures are reactive
+    let _view = view! { <p>{move || count.get()}</p> };
+
+    let _view = view! { <input value={move || name.get()} /> };
+
+    // Reads outside of `view!`
+    let doubled = count.get() * 2;
+    let _view = view! { <p>{doubled}</p> };
+
+    set_count.set(1);
+}
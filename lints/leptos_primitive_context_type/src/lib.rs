@@ -0,0 +1,208 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_hir;
+extern crate rustc_middle;
+extern crate rustc_span;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{enclosing_call, paths, resolve_str_path};
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+use serde::Deserialize;
+
+dylint_linting::impl_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for contexts provided with `provide_context` or read with
+    /// `use_context` or `expect_context` whose type is a primitive, like `&str`,
+    /// `bool` or `i32`, a collection of the standard library, like `String`,
+    /// `Vec<_>` or `HashMap<_, _>`, or a signal of one of them, like
+    /// `RwSignal<bool>`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Contexts are identified by their types, so there can only be one context
+    /// of each type in a scope. Types that any crate can use are likely to be
+    /// provided by another crate or another part of the application for some other
+    /// purpose, which silently overrides the context or makes it read a value
+    /// meant for someone else.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// provide_context("Hi!");
+    /// provide_context(RwSignal::new(false));
+    ///
+    /// let greeting = expect_context::<&str>();
+    /// let menu_open = expect_context::<RwSignal<bool>>();
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// #[derive(Clone)]
+    /// struct Greeting(&'static str);
+    ///
+    /// #[derive(Clone, Copy)]
+    /// struct MenuOpen(RwSignal<bool>);
+    ///
+    /// provide_context(Greeting("Hi!"));
+    /// provide_context(MenuOpen(RwSignal::new(false)));
+    ///
+    /// let greeting = expect_context::<Greeting>();
+    /// let menu_open = expect_context::<MenuOpen>();
+    /// ```
+    ///
+    /// ### Configuration
+    ///
+    /// - `allowed_types`: context types that are allowed, given by the path of
+    ///   the outermost type, like `std::string::String` or
+    ///   `leptos::prelude::RwSignal`, or by the name of a primitive type, like
+    ///   `bool` or `str`. References are ignored. Default: `[]`.
+    ///
+    /// ```toml
+    /// [leptos_primitive_context_type]
+    /// allowed_types = ["leptos::prelude::RwSignal"]
+    /// ```
+    pub LEPTOS_PRIMITIVE_CONTEXT_TYPE,
+    Warn,
+    "Checks for contexts whose types are primitives, collections or signals of them",
+    LeptosPrimitiveContextType::new()
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed_types: Vec<String>,
+}
+
+pub struct LeptosPrimitiveContextType {
+    config: Config,
+}
+
+impl LeptosPrimitiveContextType {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+        }
+    }
+
+    fn is_allowed(&self, cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+        let ty = ty.peel_refs();
+        self.config
+            .allowed_types
+            .iter()
+            .any(|allowed| match ty.kind() {
+                ty::Adt(adt, _) => resolve_str_path(cx.tcx, allowed).contains(&adt.did()),
+                _ => (ty.is_primitive_ty() || ty.is_str()) && ty.to_string() == *allowed,
+            })
+    }
+}
+
+impl Default for LeptosPrimitiveContextType {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosPrimitiveContextType {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        if !(paths::PROVIDE_CONTEXT.matches(cx, def_id)
+            || paths::USE_CONTEXT.matches(cx, def_id)
+            || paths::EXPECT_CONTEXT.matches(cx, def_id))
+            || !should_lint(cx.tcx, Side::Both)
+        {
+            return;
+        }
+
+        let Some(context_ty) = cx.typeck_results().node_args(expr.hir_id).types().next() else {
+            return;
+        };
+        if !is_shared_ty(cx, context_ty) || self.is_allowed(cx, context_ty) {
+            return;
+        }
+
+        let span = enclosing_call(cx, expr).map_or(expr.span, |(call, _)| call.span);
+        span_lint_and_then(
+            cx,
+            LEPTOS_PRIMITIVE_CONTEXT_TYPE,
+            span,
+            format!("use of `{context_ty}` as the type of a context"),
+            |diag| {
+                diag.note(
+                    "contexts are identified by their types, so other code using the same \
+                     type can override or read this context",
+                );
+                diag.help("wrap the value in a newtype defined for this context");
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_primitive_context_type#readme");
+            },
+        );
+    }
+}
+
+/// Check if a type is likely to be used as a context by unrelated code: a
+/// primitive, a collection of the standard library or a signal of one of them.
+fn is_shared_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    match *ty.kind() {
+        ty::Bool
+        | ty::Char
+        | ty::Int(_)
+        | ty::Uint(_)
+        | ty::Float(_)
+        | ty::Str
+        | ty::Array(..)
+        | ty::Slice(_) => true,
+        ty::Ref(_, inner, _) => is_shared_ty(cx, inner),
+        ty::Tuple(tys) => tys.iter().all(|ty| is_shared_ty(cx, ty)),
+        ty::Adt(adt, args) => {
+            let inner_is_shared = || args.types().next().is_some_and(|ty| is_shared_ty(cx, ty));
+            match cx.tcx.get_diagnostic_name(adt.did()) {
+                _ if cx.tcx.is_lang_item(adt.did(), LangItem::String) => true,
+                Some(
+                    sym::Vec
+                    | sym::VecDeque
+                    | sym::LinkedList
+                    | sym::BinaryHeap
+                    | sym::HashMap
+                    | sym::HashSet
+                    | sym::BTreeMap
+                    | sym::BTreeSet,
+                ) => true,
+                Some(sym::Option | sym::Rc | sym::Arc) => inner_is_shared(),
+                _ if adt.is_box() || paths::SIGNAL_TYPES.matches(cx, adt.did()) => {
+                    inner_is_shared()
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME"))
+            .dylint_toml(
+                r#"
+                [leptos_primitive_context_type]
+                allowed_types = ["leptos::prelude::Memo", "u8"]
+                "#,
+            )
+            .run();
+    }
+}
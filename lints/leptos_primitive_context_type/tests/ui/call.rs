@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use leptos::prelude::*;
+
+#[derive(Clone)]
+struct Greeting(&'static str);
+
+#[derive(Clone)]
+struct User {
+    _name: String,
+}
+
+fn main() {
+    provide_context("Hi!");
+    let _greeting = expect_context::<&str>();
+
+    provide_context(String::from("Hi!"));
+    let _greeting = use_context::<String>();
+
+    provide_context(true);
+    provide_context(42_i32);
+    provide_context(vec![1, 2, 3]);
+    provide_context(HashMap::<String, i32>::new());
+    provide_context(Some(1.5));
+    provide_context((1, 'a'));
+
+    let _ids = leptos::prelude::expect_context::<Rc<[i32]>>();
+    let _users = use_context::<Vec<User>>();
+
+    // Types defined for the context don't trigger the lint
+    provide_context(Greeting("Hi!"));
+    let _greeting = expect_context::<Greeting>().0;
+
+    provide_context(User {
+        _name: String::from("Ana"),
+    });
+    let _user = use_context::<Option<User>>();
+
+    provide_in_generic_fn(Greeting("Hi!"));
+}
+
+fn provide_in_generic_fn<T: Send + Sync + 'static>(value: T) {
+    provide_context(value);
+}
@@ -0,0 +1,11 @@
+use leptos::prelude::*;
+
+fn main() {
+    // Types of `allowed_types` don't trigger the lint
+    provide_context(Memo::new(|_| false));
+    let _doubled = expect_context::<Memo<i32>>();
+
+    provide_context(7_u8);
+
+    provide_context(7_u16);
+}
@@ -0,0 +1,22 @@
+use leptos::prelude::*;
+
+#[derive(Clone, Copy)]
+struct MenuOpen(RwSignal<bool>);
+
+fn main() {
+    provide_context(RwSignal::new(false));
+    let _menu_open = expect_context::<RwSignal<bool>>();
+
+    let (count, set_count) = signal(0);
+    provide_context(count);
+    provide_context(set_count);
+
+    let _name = use_context::<Signal<String>>();
+
+    // Signals wrapped in a newtype don't trigger the lint
+    provide_context(MenuOpen(RwSignal::new(false)));
+    let _menu_open = expect_context::<MenuOpen>();
+
+    // ...nor signals of specific types
+    provide_context(RwSignal::new(MenuOpen(RwSignal::new(false))));
+}
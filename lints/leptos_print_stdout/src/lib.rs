@@ -1,12 +1,21 @@
 #![feature(rustc_private)]
 #![warn(unused_extern_crates)]
 
-extern crate rustc_ast;
+extern crate rustc_data_structures;
+extern crate rustc_hir;
+extern crate rustc_span;
 
 use clippy_utils::diagnostics::span_lint_and_help;
-use rustc_lint::{EarlyContext, EarlyLintPass};
+use clippy_utils::macros::macro_backtrace;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{is_inside_paths, paths};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::ExpnId;
+use serde::Deserialize;
 
-dylint_linting::declare_pre_expansion_lint! {
+dylint_linting::impl_late_lint! {
     /// ### What it does
     ///
     /// Check for calls to `leptos::logging::log!`. The purpose of this lint is
@@ -19,12 +28,16 @@ dylint_linting::declare_pre_expansion_lint! {
     ///
     /// ### Known problems
     ///
-    /// Only catches `leptos::logging::log!` calls.
+    /// Only catches `leptos::logging::log!` calls, no matter how the macro has been
+    /// imported or renamed. Other logging macros like `leptos::logging::warn!` are not
+    /// checked.
     ///
     /// ### Example
     ///
     /// ```rust
-    /// leptos::logging::log!("This is a log message");
+    /// use leptos::logging::log;
+    ///
+    /// log!("This is a log message");
     /// ```
     ///
     /// Use instead:
@@ -36,42 +49,77 @@ dylint_linting::declare_pre_expansion_lint! {
     /// }
     /// ```
     ///
-    /// ```rust
-    /// use leptos::logging::log;
+    /// ### Configuration
     ///
-    /// log!("This is a log message");
+    /// - `allowed_paths`: modules, functions and methods where logging is fine,
+    ///   written as paths starting with the crate name. Methods are written with
+    ///   the path of their type, like `my_app::Tracer::trace`, also when they
+    ///   implement a trait. Everything defined inside them is allowed too.
+    ///   Default: `[]`.
+    ///
+    /// ```toml
+    /// [leptos_print_stdout]
+    /// allowed_paths = ["my_app::debug"]
     /// ```
     pub LEPTOS_PRINT_STDOUT,
     Warn,
-    "Check for calls to `leptos::logging::log!`"
+    "Check for calls to `leptos::logging::log!`",
+    LeptosPrintStdout::new()
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed_paths: Vec<String>,
+}
+
+pub struct LeptosPrintStdout {
+    config: Config,
+    /// Expansions of `log!` already reported.
+    ///
+    /// All the expressions produced by an expansion share its backtrace, so only
+    /// the first one visited, which is the outermost, must be reported.
+    reported_expansions: FxHashSet<ExpnId>,
+}
+
+impl LeptosPrintStdout {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+            reported_expansions: FxHashSet::default(),
+        }
+    }
 }
 
-impl EarlyLintPass for LeptosPrintStdout {
-    fn check_mac(&mut self, cx: &EarlyContext, macro_call: &rustc_ast::MacCall) {
-        let segments = &macro_call.path.segments;
-        let is_leptos_logging_log_call = (
-            // leptos::logging::log!("...")
-            segments.len() == 3
-                && segments[0].ident.name.as_str() == "leptos"
-                && segments[1].ident.name.as_str() == "logging"
-                && segments[2].ident.name.as_str() == "log"
-        ) || (
-            // ::leptos::logging::log!("...")
-            segments.len() == 4
-                && segments[0].ident.name.as_str() == "{{root}}"
-                && segments[1].ident.name.as_str() == "leptos"
-                && segments[2].ident.name.as_str() == "logging"
-                && segments[3].ident.name.as_str() == "log"
-        );
-        if is_leptos_logging_log_call {
-            span_lint_and_help(
-                cx,
-                LEPTOS_PRINT_STDOUT,
-                macro_call.span(),
-                "use of `leptos::logging::log!`",
-                None,
-                "for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_print_stdout#readme",
-            );
+impl Default for LeptosPrintStdout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosPrintStdout {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // `log!` can be expanded from inside other macros, so the whole backtrace
+        // is walked and not only the root call.
+        for macro_call in macro_backtrace(expr.span) {
+            if !paths::LOG.matches(cx, macro_call.def_id)
+                || !should_lint(cx.tcx, Side::Both)
+                || is_inside_paths(cx, expr.hir_id, &self.config.allowed_paths)
+            {
+                continue;
+            }
+
+            if self.reported_expansions.insert(macro_call.expn) {
+                span_lint_and_help(
+                    cx,
+                    LEPTOS_PRINT_STDOUT,
+                    macro_call.span,
+                    "use of `leptos::logging::log!`",
+                    None,
+                    "for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_print_stdout#readme",
+                );
+            }
         }
     }
 }
@@ -80,6 +128,18 @@ impl EarlyLintPass for LeptosPrintStdout {
 mod tests {
     #[test]
     fn ui_examples() {
-        dylint_testing::ui_test_examples(env!("CARGO_PKG_NAME"));
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME"))
+            .dylint_toml(
+                r#"
+                [leptos_print_stdout]
+                allowed_paths = [
+                    "leptos_print_stdout_config::debug",
+                    "leptos_print_stdout_config::trace_value",
+                    "leptos_print_stdout_config::Tracer::trace",
+                    "leptos_print_stdout_config::Tracer::drop",
+                ]
+                "#,
+            )
+            .run();
     }
 }
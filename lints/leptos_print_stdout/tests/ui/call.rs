@@ -1,3 +1,6 @@
+use leptos::logging::log;
+use leptos::logging::log as dbg_log;
+
 fn main() {
     leptos::logging::log!("foo");
 
@@ -9,4 +12,18 @@ fn main() {
     }
 
     ::leptos::logging::log!("bar");
+
+    log!("baz");
+
+    dbg_log!("qux");
+
+    with_prelude::log_through_prelude();
+}
+
+mod with_prelude {
+    use leptos::prelude::*;
+
+    pub fn log_through_prelude() {
+        log!("quux");
+    }
 }
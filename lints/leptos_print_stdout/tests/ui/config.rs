@@ -0,0 +1,56 @@
+use leptos::logging::log;
+
+fn main() {
+    debug::dump();
+    trace_value(1);
+    Tracer(1).trace();
+    Tracer(2).untraced();
+    not_allowed();
+}
+
+// Logging is allowed in the modules of `allowed_paths`, including its submodules
+mod debug {
+    use leptos::logging::log;
+
+    pub fn dump() {
+        log!("dump");
+        inner::dump();
+    }
+
+    mod inner {
+        pub fn dump() {
+            leptos::logging::log!("inner dump");
+        }
+    }
+}
+
+// ...and in the functions of `allowed_paths`, including their closures
+fn trace_value(value: u32) {
+    log!("value: {value}");
+    let trace = || log!("value again: {value}");
+    trace();
+}
+
+// ...and in the methods of `allowed_paths`, written with the path of their type,
+// also for trait implementations
+struct Tracer(u32);
+
+impl Tracer {
+    fn trace(&self) {
+        log!("trace {}", self.0);
+    }
+
+    fn untraced(&self) {
+        log!("not allowed in other methods {}", self.0);
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        log!("dropped {}", self.0);
+    }
+}
+
+fn not_allowed() {
+    log!("not allowed");
+}
@@ -0,0 +1,292 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_data_structures;
+extern crate rustc_errors;
+extern crate rustc_hir;
+extern crate rustc_middle;
+extern crate rustc_span;
+
+use std::ops::ControlFlow;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local;
+use clippy_utils::visitors::{Descend, for_each_expr};
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{is_from_view, paths};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::Applicability;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId, LetStmt, Node, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_span::{Span, Symbol};
+
+dylint_linting::impl_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for signals read with `.get()`, `.with()` or `.read()` by the `let`
+    /// statements of the bodies of components, outside of closures, whose values
+    /// are used in the view.
+    ///
+    /// Values computed from others that are read this way, like `label` in
+    /// `let label = format!("{doubled} clicks");`, are followed too.
+    ///
+    /// Components are told by the props that `#[component]` generates for them,
+    /// like `CounterProps` for `Counter`. Other functions, like helpers called from
+    /// the closures of a view, can run more than once, so they're not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The body of a component runs only once, when the component is created. The
+    /// value read there is the one that the signal has at that moment, so the view
+    /// keeps showing it when the signal changes.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #[component]
+    /// fn Counter(count: ReadSignal<i32>) -> impl IntoView {
+    ///     let doubled = count.get() * 2;
+    ///     view! { <p>{doubled}</p> }
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// #[component]
+    /// fn Counter(count: ReadSignal<i32>) -> impl IntoView {
+    ///     let doubled = move || count.get() * 2;
+    ///     view! { <p>{doubled}</p> }
+    /// }
+    /// ```
+    ///
+    /// Or, when the value is expensive to compute or used in several places, a
+    /// memo:
+    ///
+    /// ```rust
+    /// let doubled = Memo::new(move |_| count.get() * 2);
+    /// ```
+    pub LEPTOS_SIGNAL_READ_IN_COMPONENT_BODY,
+    Warn,
+    "Checks for signals read in the bodies of components, outside of closures",
+    LeptosSignalReadInComponentBody::default()
+}
+
+#[derive(Default)]
+pub struct LeptosSignalReadInComponentBody {
+    /// Names of the components of the crate.
+    components: FxHashSet<Symbol>,
+}
+
+/// A local whose value comes from a signal read in the body of a component.
+#[derive(Clone, Copy)]
+struct FrozenLocal<'tcx> {
+    /// The signal read.
+    read: &'tcx Expr<'tcx>,
+    /// The `let` statement that reads the signal, if it's the one that defines
+    /// the local.
+    local: Option<&'tcx LetStmt<'tcx>>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosSignalReadInComponentBody {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let tcx = cx.tcx;
+        self.components = tcx
+            .hir_crate_items(())
+            .definitions()
+            .filter(|&def_id| matches!(tcx.def_kind(def_id), DefKind::Impl { of_trait: true }))
+            .filter(|&def_id| {
+                tcx.impl_trait_ref(def_id).is_some_and(|trait_ref| {
+                    paths::COMPONENT_PROPS.matches(cx, trait_ref.skip_binder().def_id)
+                })
+            })
+            .filter_map(|def_id| match tcx.type_of(def_id).skip_binder().kind() {
+                ty::Adt(adt, _) => {
+                    let name = tcx.item_name(adt.did());
+                    name.as_str().strip_suffix("Props").map(Symbol::intern)
+                }
+                _ => None,
+            })
+            .collect();
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        _: Span,
+        _: LocalDefId,
+    ) {
+        // Views are only updated in the browser
+        if !self.is_component(kind) || !should_lint(cx.tcx, Side::Client) {
+            return;
+        }
+        let ExprKind::Block(block, _) = body.value.kind else {
+            return;
+        };
+
+        let mut frozen = FxHashMap::<HirId, FrozenLocal<'tcx>>::default();
+        for stmt in block.stmts {
+            let StmtKind::Let(local) = stmt.kind else {
+                continue;
+            };
+            let Some(init) = local.init else {
+                continue;
+            };
+            let origin = if let Some(read) = eager_read(cx, init) {
+                FrozenLocal {
+                    read,
+                    local: Some(local),
+                }
+            } else if let Some(origin) = frozen_local_use(cx, &frozen, init) {
+                FrozenLocal {
+                    read: origin.read,
+                    local: None,
+                }
+            } else {
+                continue;
+            };
+            local.pat.each_binding(|_, hir_id, _, _| {
+                frozen.insert(hir_id, origin);
+            });
+        }
+        if frozen.is_empty() {
+            return;
+        }
+
+        // Each read is reported with the first use in the view of a value that
+        // comes from it
+        let mut reported = FxHashSet::default();
+        for_each_expr(cx, body.value, |expr| {
+            if let Some(binding) = path_to_local(expr)
+                && let Some(origin) = frozen.get(&binding)
+                && is_in_view(cx, expr)
+                && reported.insert(origin.read.hir_id)
+            {
+                report(cx, *origin, expr);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+}
+
+impl LeptosSignalReadInComponentBody {
+    /// Check if a function is a component, or the inner function that
+    /// `#[component]` moves its body to, like `__Counter` for `Counter`.
+    fn is_component(&self, kind: FnKind<'_>) -> bool {
+        let FnKind::ItemFn(ident, ..) = kind else {
+            return false;
+        };
+        let name = ident.name.as_str();
+        let name = name.strip_prefix("__").unwrap_or(name);
+        self.components.contains(&Symbol::intern(name))
+    }
+}
+
+/// First signal read evaluated by an expression, outside of closures.
+fn eager_read<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    for_each_expr(cx, expr, |expr| {
+        if let ExprKind::Closure(_) = expr.kind {
+            return ControlFlow::Continue(Descend::No);
+        }
+        if let ExprKind::MethodCall(..) = expr.kind
+            && !expr.span.from_expansion()
+            && cx
+                .typeck_results()
+                .type_dependent_def_id(expr.hir_id)
+                .is_some_and(|def_id| paths::TRACKED_SIGNAL_READS.matches(cx, def_id))
+        {
+            return ControlFlow::Break(expr);
+        }
+        ControlFlow::Continue(Descend::Yes)
+    })
+}
+
+/// First local used by an expression whose value comes from a signal read.
+fn frozen_local_use<'tcx>(
+    cx: &LateContext<'tcx>,
+    frozen: &FxHashMap<HirId, FrozenLocal<'tcx>>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<FrozenLocal<'tcx>> {
+    for_each_expr(cx, expr, |expr| {
+        match path_to_local(expr).and_then(|binding| frozen.get(&binding)) {
+            Some(origin) => ControlFlow::Break(*origin),
+            None => ControlFlow::Continue(()),
+        }
+    })
+}
+
+/// Check if an expression written by the user is part of a value of `view!`,
+/// including the closures of the view.
+fn is_in_view(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    !expr.span.from_expansion()
+        && cx
+            .tcx
+            .hir_parent_iter(expr.hir_id)
+            .map_while(|(_, node)| match node {
+                Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => None,
+                _ => Some(node),
+            })
+            .any(|node| matches!(node, Node::Expr(parent) if is_from_view(cx, parent.span)))
+}
+
+/// Report a signal read, with the first use in the view of a value that comes
+/// from it.
+fn report<'tcx>(cx: &LateContext<'tcx>, origin: FrozenLocal<'tcx>, view_use: &Expr<'_>) {
+    span_lint_and_then(
+        cx,
+        LEPTOS_SIGNAL_READ_IN_COMPONENT_BODY,
+        origin.read.span,
+        "signal read in the body of a component, which runs only once",
+        |diag| {
+            let note = if origin.local.is_some() {
+                "the value is used in the view here, and isn't updated when the signal changes"
+            } else {
+                "a value computed from it is used in the view here, and isn't updated when the \
+                 signal changes"
+            };
+            diag.span_note(view_use.span, note);
+            // Patterns like tuples can't be bound to a closure or a memo
+            let init = origin
+                .local
+                .filter(|local| matches!(local.pat.kind, PatKind::Binding(.., None)))
+                .and_then(|local| local.init);
+            match init {
+                Some(init) if !init.span.from_expansion() => {
+                    diag.span_suggestion_verbose(
+                        init.span.shrink_to_lo(),
+                        "derive the value in a closure",
+                        "move || ",
+                        Applicability::MaybeIncorrect,
+                    );
+                    diag.multipart_suggestion_verbose(
+                        "or compute it in a memo",
+                        vec![
+                            (init.span.shrink_to_lo(), "Memo::new(move |_| ".to_string()),
+                            (init.span.shrink_to_hi(), ")".to_string()),
+                        ],
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                _ => {
+                    diag.help("derive the value in a closure, or compute it with `Memo::new`");
+                }
+            }
+            diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_signal_read_in_component_body#readme");
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
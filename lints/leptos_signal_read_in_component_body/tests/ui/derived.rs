@@ -0,0 +1,38 @@
+#![allow(non_snake_case)]
+
+use leptos::html::{HtmlElement, P};
+use leptos::prelude::*;
+
+// What `#[component]` generates for the props of the components, without their
+// builders
+macro_rules! props {
+    ($($props:ident),*) => {$(
+        #[allow(dead_code)]
+        struct $props;
+
+        impl leptos::component::Props for $props {
+            type Builder = ();
+
+            fn builder() {}
+        }
+    )*};
+}
+
+props!(CartProps, DiscountProps);
+
+fn Cart(price: Memo<u32>, quantity: u32) -> HtmlElement<P> {
+    let total = price.get() * quantity;
+    let label = format!("Total: {total}");
+    view! { <p>{label}</p> }
+}
+
+fn Discount(price: Signal<u32>) -> HtmlElement<P> {
+    let full = price.get();
+    let discounted = full * 9 / 10;
+    view! { <p class="price">{full} " " {discounted}</p> }
+}
+
+fn main() {
+    let price = Memo::new(|_| 10);
+    let _views = (Cart(price, 2), Discount(Signal::derive(|| 10)));
+}
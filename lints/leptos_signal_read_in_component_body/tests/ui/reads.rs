@@ -0,0 +1,92 @@
+#![allow(non_snake_case)]
+
+use leptos::html::{Div, HtmlElement, P};
+use leptos::prelude::*;
+
+// What `#[component]` generates for the props of the components, without their
+// builders
+macro_rules! props {
+    ($($props:ident),*) => {$(
+        #[allow(dead_code)]
+        struct $props;
+
+        impl leptos::component::Props for $props {
+            type Builder = ();
+
+            fn builder() {}
+        }
+    )*};
+}
+
+props!(
+    CounterProps,
+    GreetingProps,
+    ReactiveProps,
+    InitialProps,
+    LoggerProps,
+    ListProps
+);
+
+// The body of a component is kept by `#[component]` in an inner function
+fn Counter(_props: CounterProps) -> HtmlElement<P> {
+    fn __Counter(count: ReadSignal<i32>) -> HtmlElement<P> {
+        let doubled = count.get() * 2;
+        view! { <p>{doubled}</p> }
+    }
+    __Counter(signal(0).0)
+}
+
+fn Greeting(name: RwSignal<String>) -> HtmlElement<Div> {
+    let greeting = name.with(|name| format!("Hello, {name}!"));
+    let (length, empty) = (name.read().len(), name.read().is_empty());
+    view! {
+        <div title=greeting>
+            <span>{move || length}</span>
+            <span>{empty}</span>
+        </div>
+    }
+}
+
+// Reads in closures, memos and derived signals are reactive
+fn Reactive(count: ReadSignal<i32>) -> HtmlElement<P> {
+    let doubled = move || count.get() * 2;
+    let tripled = Memo::new(move |_| count.get() * 3);
+    let halved = Signal::derive(move || count.get() / 2);
+    view! { <p>{doubled} {tripled} {halved}</p> }
+}
+
+// Untracked reads are not meant to be reactive
+fn Initial(count: ReadSignal<i32>) -> HtmlElement<P> {
+    let initial = count.get_untracked();
+    view! { <p>{initial}</p> }
+}
+
+// Values that are not used in the view
+fn Logger(count: ReadSignal<i32>, set_total: WriteSignal<i32>) -> HtmlElement<P> {
+    let start = count.get();
+    set_total.set(start);
+    view! { <p>"Logged"</p> }
+}
+
+// Functions that aren't components, like helpers called from the closures of a
+// view, can run more than once
+fn row(item: ReadSignal<i32>) -> HtmlElement<P> {
+    let value = item.get();
+    view! { <p>{value}</p> }
+}
+
+fn List(item: ReadSignal<i32>) -> HtmlElement<Div> {
+    view! { <div>{move || row(item)}</div> }
+}
+
+fn main() {
+    let (count, set_count) = signal(0);
+    let _views = (
+        Counter(CounterProps),
+        Greeting(RwSignal::new(String::new())),
+        Reactive(count),
+        Initial(count),
+        Logger(count, set_count),
+        List(count),
+    );
+}
@@ -0,0 +1,552 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_hir;
+extern crate rustc_middle;
+extern crate rustc_span;
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+
+use clippy_utils::visitors::for_each_expr;
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::{enclosing_call, paths};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{self, EarlyBinder, GenericArgsRef, Ty, TyCtxt, TypeVisitableExt};
+use rustc_session::config::CrateType;
+use rustc_span::{FileName, Span};
+
+dylint_linting::impl_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks the contexts of all the crates of a workspace, reporting the types
+    /// read with `use_context` or `expect_context` that are never provided with
+    /// `provide_context`, and the types provided that are never read.
+    ///
+    /// Each crate writes a summary of the contexts that it provides and reads to
+    /// the target directory when it's compiled. The summaries are merged when the
+    /// lint runs on a binary crate, or on a `cdylib` crate that no other crate of
+    /// the workspace depends on, like the server or the client of an app, with the
+    /// ones of the crates that it depends on, which are compiled before it. The
+    /// library of a package with binaries, like the one of the single package
+    /// layout of `cargo-leptos`, is compiled as a `cdylib` too, but its summary is
+    /// only merged by the binaries.
+    ///
+    /// Only contexts whose types are defined in crates of the workspace, the ones
+    /// whose sources are in the directory of the workspace, are checked, as
+    /// contexts of other types can be provided or read by dependencies, like
+    /// `leptos_router` or `leptos_meta`. The crates of Leptos are never crates of
+    /// the workspace. Only the outermost type of a context is considered, so
+    /// contexts like `RwSignal<Theme>` are not checked, even when `Theme` is
+    /// defined in the workspace.
+    ///
+    /// Functions with type parameters that provide or read contexts of those types,
+    /// like `fn provide_settings<T>(value: T)`, are checked at their calls.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A context that is read but never provided is never found, so
+    /// `expect_context` panics at run-time and `use_context` always returns `None`.
+    /// This usually happens when the call to `provide_context` is removed or moved
+    /// behind a feature, and it's not noticed until the code runs in production.
+    ///
+    /// A context that is provided but never read is dead code.
+    ///
+    /// ### Known problems
+    ///
+    /// Contexts of the type parameters of generic functions are only known at the
+    /// calls of the functions of the same crate that give them a concrete type.
+    /// When the function is called from another generic function, or from another
+    /// crate, the context is missed.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// // In the `ui` crate
+    /// #[component]
+    /// pub fn ThemeToggle() -> impl IntoView {
+    ///     let theme = expect_context::<Theme>();
+    ///     // ...
+    /// }
+    ///
+    /// // In the `app` crate, nothing provides a `Theme`
+    /// #[component]
+    /// pub fn App() -> impl IntoView {
+    ///     view! { <ui::ThemeToggle /> }
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// #[component]
+    /// pub fn App() -> impl IntoView {
+    ///     provide_context(Theme::default());
+    ///     view! { <ui::ThemeToggle /> }
+    /// }
+    /// ```
+    pub LEPTOS_UNMATCHED_CONTEXT,
+    Warn,
+    "Checks for contexts that are read but never provided, or provided but never read",
+    LeptosUnmatchedContext::default()
+}
+
+#[derive(Default)]
+pub struct LeptosUnmatchedContext {
+    accesses: Vec<ContextAccess>,
+}
+
+impl LeptosUnmatchedContext {
+    fn push<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        access: Access,
+        context_ty: Ty<'tcx>,
+        span: Span,
+    ) {
+        // Contexts of generic types are provided or read by generic functions, that
+        // can be used with any type
+        let ty::Adt(adt, _) = context_ty.kind() else {
+            return;
+        };
+
+        let loc = cx.sess().source_map().lookup_char_pos(span.lo());
+        let context_ty = cx.tcx.erase_regions(context_ty);
+        self.accesses.push(ContextAccess {
+            access,
+            krate: cx.tcx.crate_name(adt.did().krate).to_string(),
+            type_id: cx.tcx.type_id_hash(context_ty).as_u128(),
+            ty: with_no_trimmed_paths!(context_ty.to_string()),
+            location: format!(
+                "{}:{}:{}",
+                loc.file.name.prefer_local(),
+                loc.line,
+                loc.col_display + 1
+            ),
+            span: Some(span),
+        });
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Access {
+    Provide,
+    Read,
+}
+
+/// A context provided or read by a crate, as written in its summary.
+#[derive(Debug, PartialEq, Eq)]
+struct ContextAccess {
+    access: Access,
+    /// Crate that defines the outermost type of the context.
+    krate: String,
+    /// Hash of the type of the context, the same used for its `TypeId`, which
+    /// identifies the context.
+    type_id: u128,
+    /// Type of the context, to be displayed.
+    ty: String,
+    /// Location of the access, like `src/lib.rs:12:5`.
+    location: String,
+    /// Span of the access, only known for the crate being compiled.
+    span: Option<Span>,
+}
+
+impl ContextAccess {
+    fn to_line(&self) -> String {
+        let access = match self.access {
+            Access::Provide => "provide",
+            Access::Read => "read",
+        };
+        format!(
+            "{access}\t{}\t{:032x}\t{}\t{}",
+            self.krate, self.type_id, self.ty, self.location
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let access = match fields.next()? {
+            "provide" => Access::Provide,
+            "read" => Access::Read,
+            _ => return None,
+        };
+        Some(Self {
+            access,
+            krate: fields.next()?.to_string(),
+            type_id: u128::from_str_radix(fields.next()?, 16).ok()?,
+            ty: fields.next()?.to_string(),
+            location: fields.next()?.to_string(),
+            span: None,
+        })
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosUnmatchedContext {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        let span = enclosing_call(cx, expr)
+            .map_or(expr.span, |(call, _)| call.span)
+            .source_callsite();
+        if let Some(access) = context_access(cx, def_id) {
+            let Some(context_ty) = cx.typeck_results().node_args(expr.hir_id).types().next() else {
+                return;
+            };
+            self.push(cx, access, context_ty, span);
+        } else if matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+            let args = cx.typeck_results().node_args(expr.hir_id);
+            for (access, context_ty) in generic_accesses(cx, def_id, args) {
+                self.push(cx, access, context_ty, span);
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let tcx = cx.tcx;
+        let dir = summaries_dir(tcx);
+
+        // Without the summary, the crates depending on this one only miss some
+        // contexts, so errors writing it are not reported
+        if let Some(dir) = &dir {
+            let summary = self
+                .accesses
+                .iter()
+                .map(|access| access.to_line() + "\n")
+                .collect::<String>();
+            let _ = fs::create_dir_all(dir)
+                .and_then(|()| fs::write(dir.join(summary_file_name(tcx, LOCAL_CRATE)), summary));
+        }
+
+        // Only the crates at the top of the dependency graph see all the contexts.
+        // Libraries are compiled as `cdylib` even when other crates, or the
+        // binaries of their package, depend on them.
+        let crate_types = tcx.crate_types();
+        let is_root = crate_types.contains(&CrateType::Executable)
+            || crate_types.contains(&CrateType::Cdylib)
+                && !is_workspace_dependency(tcx)
+                && !is_package_dependency();
+        if !is_root || !should_lint(tcx, Side::Both) {
+            return;
+        }
+
+        let mut accesses = std::mem::take(&mut self.accesses);
+        if let Some(dir) = &dir {
+            for &cnum in tcx.crates(()) {
+                if let Ok(summary) = fs::read_to_string(dir.join(summary_file_name(tcx, cnum))) {
+                    accesses.extend(summary.lines().filter_map(ContextAccess::from_line));
+                }
+            }
+        }
+        let workspace_crates = std::iter::once(LOCAL_CRATE)
+            .chain(tcx.crates(()).iter().copied())
+            .filter(|&cnum| is_workspace_crate(tcx, cnum))
+            .map(|cnum| tcx.crate_name(cnum).to_string())
+            .collect::<BTreeSet<_>>();
+
+        let types_with = |access: Access| {
+            accesses
+                .iter()
+                .filter(|a| a.access == access)
+                .map(|a| a.type_id)
+                .collect::<BTreeSet<_>>()
+        };
+        let provided = types_with(Access::Provide);
+        let read = types_with(Access::Read);
+
+        for access in &accesses {
+            if !workspace_crates.contains(&access.krate) {
+                continue;
+            }
+            let (message, note) = match access.access {
+                Access::Read if !provided.contains(&access.type_id) => (
+                    format!("context of type `{}` is read but never provided", access.ty),
+                    "no crate of the workspace provides it",
+                ),
+                Access::Provide if !read.contains(&access.type_id) => (
+                    format!("context of type `{}` is provided but never read", access.ty),
+                    "no crate of the workspace reads it",
+                ),
+                _ => continue,
+            };
+            cx.opt_span_lint(LEPTOS_UNMATCHED_CONTEXT, access.span, |diag| {
+                diag.primary_message(message);
+                if access.span.is_none() {
+                    diag.note(format!("at {}", access.location));
+                }
+                diag.note(note);
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_unmatched_context#readme");
+            });
+        }
+    }
+}
+
+/// Kind of access to a context of the functions that provide and read them.
+fn context_access(cx: &LateContext<'_>, def_id: DefId) -> Option<Access> {
+    if paths::PROVIDE_CONTEXT.matches(cx, def_id) {
+        Some(Access::Provide)
+    } else if paths::USE_CONTEXT.matches(cx, def_id) || paths::EXPECT_CONTEXT.matches(cx, def_id) {
+        Some(Access::Read)
+    } else {
+        None
+    }
+}
+
+/// Contexts of the type parameters of a local function that it provides or reads
+/// itself, with the types given by the arguments of a call.
+fn generic_accesses<'tcx>(
+    cx: &LateContext<'tcx>,
+    def_id: DefId,
+    args: GenericArgsRef<'tcx>,
+) -> Vec<(Access, Ty<'tcx>)> {
+    let tcx = cx.tcx;
+    let Some(local_def_id) = def_id.as_local() else {
+        return Vec::new();
+    };
+    if args.types().next().is_none() {
+        return Vec::new();
+    }
+    let Some(body) = tcx.hir_maybe_body_owned_by(local_def_id) else {
+        return Vec::new();
+    };
+
+    let typeck_results = tcx.typeck(local_def_id);
+    let mut accesses = Vec::new();
+    for_each_expr(cx, body.value, |expr| {
+        if let ExprKind::Path(ref qpath) = expr.kind
+            && let Some(callee) = typeck_results.qpath_res(qpath, expr.hir_id).opt_def_id()
+            && let Some(access) = context_access(cx, callee)
+            && let Some(context_ty) = typeck_results.node_args(expr.hir_id).types().next()
+            && context_ty.has_param()
+        {
+            accesses.push((access, EarlyBinder::bind(context_ty).instantiate(tcx, args)));
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    accesses
+}
+
+/// Check if a crate is one of the workspace, whose sources are in the directory
+/// where Cargo compiles, the root of the workspace. The crates of Leptos, like
+/// its mocks in the workspace of these lints, are never crates of the workspace.
+fn is_workspace_crate(tcx: TyCtxt<'_>, cnum: CrateNum) -> bool {
+    if paths::KNOWN_CONTEXT_CRATES.contains(&tcx.crate_name(cnum).as_str()) {
+        return false;
+    }
+    let FileName::Real(file_name) = tcx
+        .sess
+        .source_map()
+        .span_to_filename(tcx.def_span(cnum.as_def_id()))
+    else {
+        return false;
+    };
+    let (Some(path), Some(workspace_dir)) = (
+        file_name.local_path(),
+        tcx.sess.opts.working_dir.local_path(),
+    ) else {
+        return false;
+    };
+    // Relative paths are relative to the working directory
+    workspace_dir.join(path).starts_with(workspace_dir)
+}
+
+/// Check if another member of the workspace depends on the package being
+/// compiled, with its `[dependencies]` or its target-specific dependencies.
+///
+/// Members are read from the `[workspace]` table of the manifest of the root of
+/// the workspace, where Cargo compiles. Only globs ending in `/*` are supported.
+fn is_workspace_dependency(tcx: TyCtxt<'_>) -> bool {
+    let (Ok(package), Ok(package_dir), Some(workspace_dir)) = (
+        std::env::var("CARGO_PKG_NAME"),
+        std::env::var("CARGO_MANIFEST_DIR"),
+        tcx.sess.opts.working_dir.local_path(),
+    ) else {
+        return false;
+    };
+    let Some(members) = read_manifest(workspace_dir).and_then(|manifest| {
+        manifest
+            .get("workspace")?
+            .get("members")?
+            .as_array()
+            .cloned()
+    }) else {
+        return false;
+    };
+
+    members
+        .iter()
+        .filter_map(toml::Value::as_str)
+        .flat_map(|member| match member.strip_suffix("/*") {
+            Some(parent) => fs::read_dir(workspace_dir.join(parent))
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .collect(),
+            None => vec![workspace_dir.join(member)],
+        })
+        .filter(|dir| !same_dir(dir, Path::new(&package_dir)))
+        .filter_map(|dir| read_manifest(&dir))
+        .any(|manifest| depends_on(&manifest, &package))
+}
+
+/// Check if the binaries of the package being compiled depend on it, like the
+/// server of the single package layout of `cargo-leptos`, whose library is also
+/// compiled as a `cdylib` for the client.
+fn is_package_dependency() -> bool {
+    std::env::var("CARGO_MANIFEST_DIR").is_ok_and(|dir| has_bin(Path::new(&dir)))
+}
+
+/// Check if a package has binary targets, declared in its manifest or found by
+/// Cargo at `src/main.rs` or `src/bin`.
+fn has_bin(package_dir: &Path) -> bool {
+    let Some(manifest) = read_manifest(package_dir) else {
+        return false;
+    };
+    if manifest
+        .get("bin")
+        .and_then(toml::Value::as_array)
+        .is_some_and(|bins| !bins.is_empty())
+    {
+        return true;
+    }
+    let autobins = manifest
+        .get("package")
+        .and_then(|package| package.get("autobins"))
+        .and_then(toml::Value::as_bool)
+        .unwrap_or(true);
+    autobins
+        && (package_dir.join("src").join("main.rs").is_file()
+            || package_dir.join("src").join("bin").is_dir())
+}
+
+/// Check if a manifest has a package in its `[dependencies]` or its
+/// target-specific dependencies, even when the dependency is renamed.
+fn depends_on(manifest: &toml::Table, package: &str) -> bool {
+    let target_dependencies = manifest
+        .get("target")
+        .and_then(toml::Value::as_table)
+        .into_iter()
+        .flat_map(|targets| targets.values())
+        .filter_map(|target| target.get("dependencies"));
+    manifest
+        .get("dependencies")
+        .into_iter()
+        .chain(target_dependencies)
+        .filter_map(toml::Value::as_table)
+        .flatten()
+        .any(|(name, dependency)| {
+            dependency
+                .get("package")
+                .and_then(toml::Value::as_str)
+                .unwrap_or(name)
+                == package
+        })
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn read_manifest(dir: &Path) -> Option<toml::Table> {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Directory of the target directory where the crates write their summaries,
+/// next to the compiled crates.
+fn summaries_dir(tcx: TyCtxt<'_>) -> Option<PathBuf> {
+    let out_dir = tcx.sess.io.output_dir.as_deref()?;
+    // Cargo puts the dependencies in `deps` and the examples in `examples`, next
+    // to each other
+    let target_dir = match out_dir.file_name().and_then(|name| name.to_str()) {
+        Some("deps" | "examples") => out_dir.parent()?,
+        _ => out_dir,
+    };
+    Some(target_dir.join("leptos_lints").join("contexts"))
+}
+
+/// Name of the summary file of a crate. Crates compiled with different features
+/// or for different targets have different ids.
+fn summary_file_name(tcx: TyCtxt<'_>, cnum: CrateNum) -> String {
+    format!(
+        "{}-{:016x}.txt",
+        tcx.crate_name(cnum),
+        tcx.stable_crate_id(cnum).as_u64()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Access, ContextAccess, depends_on, has_bin};
+
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+
+    #[test]
+    fn summary_lines_roundtrip() {
+        let access = ContextAccess {
+            access: Access::Read,
+            krate: "ui".to_string(),
+            type_id: 0x0123_4567_89ab_cdef_0123_4567_89ab_cdef,
+            ty: "ui::Theme".to_string(),
+            location: "ui/src/toggle.rs:12:17".to_string(),
+            span: None,
+        };
+        assert_eq!(ContextAccess::from_line(&access.to_line()), Some(access));
+        assert_eq!(
+            ContextAccess::from_line("use\tui\t0\tui::Theme\tsrc/lib.rs:1:1"),
+            None
+        );
+    }
+
+    #[test]
+    fn workspace_dependencies() {
+        let manifest = r#"
+            [dependencies]
+            leptos = "0.8"
+            ui = { path = "../ui" }
+            core = { package = "app-core", path = "../core" }
+
+            [target.'cfg(target_arch = "wasm32")'.dependencies]
+            hydrate = { path = "../hydrate" }
+
+            [dev-dependencies]
+            testing = { path = "../testing" }
+        "#
+        .parse::<toml::Table>()
+        .unwrap();
+        assert!(depends_on(&manifest, "ui"));
+        assert!(depends_on(&manifest, "app-core"));
+        assert!(depends_on(&manifest, "hydrate"));
+        assert!(!depends_on(&manifest, "core"));
+        assert!(!depends_on(&manifest, "testing"));
+        assert!(!depends_on(&manifest, "server"));
+    }
+
+    #[test]
+    fn package_binaries() {
+        let tests_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+        assert!(has_bin(&tests_dir.join("single_package")));
+        assert!(!has_bin(&tests_dir.join("components")));
+    }
+}
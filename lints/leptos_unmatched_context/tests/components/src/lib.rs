@@ -0,0 +1,25 @@
+//! Components of another crate of the workspace, whose contexts are merged with
+//! the ones of the UI tests.
+
+use leptos::prelude::*;
+
+#[derive(Clone)]
+pub struct Theme;
+
+#[derive(Clone)]
+pub struct Sidebar;
+
+#[derive(Clone)]
+pub struct Session;
+
+pub fn theme_toggle() {
+    let _theme = expect_context::<Theme>();
+}
+
+pub fn provide_sidebar() {
+    provide_context(Sidebar);
+}
+
+pub fn session_menu() {
+    let _session = use_context::<Session>();
+}
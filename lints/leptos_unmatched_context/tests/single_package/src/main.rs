@@ -0,0 +1,7 @@
+#[cfg(feature = "ssr")]
+fn main() {
+    // The server renders `single_package::App`
+}
+
+#[cfg(not(feature = "ssr"))]
+fn main() {}
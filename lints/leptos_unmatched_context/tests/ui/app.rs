@@ -0,0 +1,36 @@
+use leptos::prelude::*;
+
+#[derive(Clone)]
+struct Theme;
+
+#[derive(Clone)]
+struct Locale;
+
+#[derive(Clone)]
+struct User;
+
+#[derive(Clone, Copy)]
+struct MenuOpen(RwSignal<bool>);
+
+fn main() {
+    // Contexts that are provided and read don't trigger the lint
+    provide_context(Theme);
+    let _theme = expect_context::<Theme>();
+
+    provide_context(MenuOpen(RwSignal::new(false)));
+    let _menu_open = use_context::<MenuOpen>();
+
+    // ...even when they're provided by generic functions
+    let _locale = expect_context::<Locale>();
+    let _locale = use_context::<Locale>();
+    provide_generic(Locale);
+
+    provide_context(User);
+
+    // ...nor contexts of types defined outside of the workspace
+    let _user = use_context::<RwSignal<User>>();
+}
+
+fn provide_generic<T: Send + Sync + 'static>(value: T) {
+    provide_context(value);
+}
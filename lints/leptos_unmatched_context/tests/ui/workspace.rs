@@ -0,0 +1,17 @@
+use leptos::prelude::*;
+use leptos_unmatched_context_components::{
+    Sidebar, Theme, provide_sidebar, session_menu, theme_toggle,
+};
+
+fn main() {
+    // Contexts of other crates of the workspace are matched with the ones of the
+    // crate being compiled
+    provide_context(Theme);
+    theme_toggle();
+
+    provide_sidebar();
+    let _sidebar = expect_context::<Sidebar>();
+
+    // ...and reported when they're not
+    session_menu();
+}
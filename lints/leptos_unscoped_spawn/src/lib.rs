@@ -1,18 +1,33 @@
 #![feature(rustc_private)]
 #![warn(unused_extern_crates)]
 
+extern crate rustc_errors;
 extern crate rustc_hir;
 
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_then;
+use leptos_lints_utils::context::{ContextUse, future_context_use};
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::sugg::replace_fn_path;
+use leptos_lints_utils::version::{CONTEXTS_IN_EVENT_LISTENERS, leptos_is_older_than};
+use leptos_lints_utils::{enclosing_call, is_in_event_listener, is_inside_paths, paths};
+use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
+use serde::Deserialize;
 
-dylint_linting::declare_late_lint! {
+dylint_linting::impl_late_lint! {
     /// ### What it does
     ///
-    /// Forbids the use of `leptos::task::spawn_local`, and recommends using
-    /// `leptos::task::spawn_local_scoped` instead.  The purpose of this lint is
-    /// to reduce the risk of missing context at run-time.
+    /// Forbids the use of `leptos::task::spawn_local` with futures that read
+    /// contexts, and recommends using `leptos::task::spawn_local_scoped` instead.
+    /// The purpose of this lint is to reduce the risk of missing context at run-time.
+    ///
+    /// The spawned future is walked, with the local functions and closures that it
+    /// calls, looking for `use_context`, `expect_context` and hooks of other crates
+    /// like `use_navigate`, which read contexts. The lint is also triggered when the
+    /// future calls code that can't be followed, like function pointers or trait
+    /// objects, or when the future itself can't be followed, like when it's passed
+    /// in a variable holding a boxed future.
     ///
     /// ### Why is this bad?
     ///
@@ -24,10 +39,15 @@ dylint_linting::declare_late_lint! {
     ///
     /// [`leptos_unscoped_spawn`'s rationale]: https://github.com/leptos-rs/leptos-lints/blob/main/lints/leptos_unscoped_spawn/RATIONALE.md
     ///
+    /// Before Leptos v0.8.9, contexts are not available in event listeners, so a
+    /// scoped spawn inside them would miss the contexts too. For these versions, the
+    /// lint explains how to pass the contexts to the spawn instead.
+    ///
     /// ### Example
     ///
     /// ```rust
     /// leptos::task::spawn_local(async {
+    ///     let user = expect_context::<User>();
     ///     // ...
     /// });
     /// ```
@@ -36,12 +56,16 @@ dylint_linting::declare_late_lint! {
     ///
     /// ```rust
     /// leptos::task::spawn_local_scoped(async {
+    ///     let user = expect_context::<User>();
     ///     // ...
     /// });
     /// ```
     ///
-    /// A scoped spawn is less performant, so if you're sure that you're not using contexts
-    /// in the spawn, allow the spawn to be unscoped.
+    /// The lint provides a fix that can be applied automatically with
+    /// `cargo dylint --fix`.
+    ///
+    /// A scoped spawn is less performant, so if you're sure that the code that can't
+    /// be followed doesn't use contexts, allow the spawn to be unscoped.
     ///
     /// ```rust
     /// #[allow(leptos_unscoped_spawn)]
@@ -49,18 +73,52 @@ dylint_linting::declare_late_lint! {
     ///     // ...
     /// });
     /// ```
+    ///
+    /// ### Configuration
+    ///
+    /// - `allowed_in`: functions, methods and modules known not to need contexts
+    ///   in their spawns, written as paths starting with the crate name. Methods
+    ///   are written with the path of their type, like `my_app::Analytics::send`.
+    ///   Unscoped spawns inside them are allowed. Default: `[]`.
+    ///
+    /// ```toml
+    /// [leptos_unscoped_spawn]
+    /// allowed_in = ["my_app::analytics::send_event"]
+    /// ```
     pub LEPTOS_UNSCOPED_SPAWN,
     Warn,
-    "Forbids the use of `leptos::task::spawn_local`"
+    "Forbids the use of `leptos::task::spawn_local`",
+    LeptosUnscopedSpawn::new()
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed_in: Vec<String>,
+}
+
+pub struct LeptosUnscopedSpawn {
+    config: Config,
+}
+
+impl LeptosUnscopedSpawn {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+        }
+    }
+}
+
+impl Default for LeptosUnscopedSpawn {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'tcx> LateLintPass<'tcx> for LeptosUnscopedSpawn {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
-        let ExprKind::Call(func, _) = expr.kind else {
-            return;
-        };
-
-        let ExprKind::Path(ref qpath) = func.kind else {
+        let ExprKind::Path(ref qpath) = expr.kind else {
             return;
         };
 
@@ -68,21 +126,69 @@ impl<'tcx> LateLintPass<'tcx> for LeptosUnscopedSpawn {
             return;
         };
 
-        if cx.tcx.item_name(def_id).as_str() != "spawn_local" {
+        if !paths::SPAWN_LOCAL.matches(cx, def_id)
+            || !should_lint(cx.tcx, Side::Both)
+            || is_inside_paths(cx, expr.hir_id, &self.config.allowed_in)
+        {
             return;
         }
 
-        let QPath::Resolved(_, path) = qpath else {
-            return;
+        // Spawns that are not called, like when passed as a value, can't be followed
+        let context_use = match enclosing_call(cx, expr) {
+            Some((_, [future])) => match future_context_use(cx, future) {
+                Some(context_use) => Some(context_use),
+                None => return,
+            },
+            _ => None,
         };
 
-        span_lint_and_help(
+        span_lint_and_then(
             cx,
             LEPTOS_UNSCOPED_SPAWN,
-            path.span,
+            expr.span,
             "use of `leptos::task::spawn_local`",
-            None,
-            "prefer `leptos::task::spawn_local_scoped`. For further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_unscoped_spawn#readme",
+            |diag| {
+                match context_use {
+                    Some(ContextUse::Read(span)) => {
+                        diag.span_note(span, "a context is read here");
+                    }
+                    Some(ContextUse::Unknown(span)) => {
+                        diag.span_note(span, "this can't be followed, so it may read a context");
+                    }
+                    Some(ContextUse::Node(_)) | None => {}
+                }
+
+                // Before Leptos 0.8.9, event listeners don't have an owner, so
+                // scoping the spawn doesn't bring the contexts back.
+                if is_in_event_listener(cx, expr.hir_id)
+                    && leptos_is_older_than(cx.tcx, CONTEXTS_IN_EVENT_LISTENERS)
+                {
+                    diag.note(format!(
+                        "contexts are not available in event listeners before Leptos \
+                         {CONTEXTS_IN_EVENT_LISTENERS}, so a scoped spawn would miss them too"
+                    ));
+                    diag.help("read the contexts outside of the event listener and move them into the spawn");
+                    diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_unscoped_spawn#readme");
+                    return;
+                }
+
+                let edits = match qpath {
+                    QPath::Resolved(None, path) => {
+                        replace_fn_path(cx, path, expr.hir_id, "spawn_local_scoped")
+                    }
+                    _ => None,
+                };
+                if let Some(edits) = edits {
+                    diag.multipart_suggestion(
+                        "use a scoped spawn",
+                        edits,
+                        Applicability::MachineApplicable,
+                    );
+                } else {
+                    diag.help("prefer `leptos::task::spawn_local_scoped`");
+                }
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_unscoped_spawn#readme");
+            },
         );
     }
 }
@@ -91,6 +197,16 @@ impl<'tcx> LateLintPass<'tcx> for LeptosUnscopedSpawn {
 mod tests {
     #[test]
     fn ui_examples() {
-        dylint_testing::ui_test_examples(env!("CARGO_PKG_NAME"));
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME"))
+            .dylint_toml(
+                r#"
+                [leptos_unscoped_spawn]
+                allowed_in = [
+                    "leptos_unscoped_spawn_config::analytics",
+                    "leptos_unscoped_spawn_config::prefetch",
+                ]
+                "#,
+            )
+            .run();
     }
 }
@@ -1,20 +1,84 @@
+use leptos::prelude::expect_context;
 use leptos::task;
 use leptos::task::spawn_local;
 
 fn main() {
     spawn_local(async {
-        // ...
+        let _theme = expect_context::<Theme>();
     });
 
     task::spawn_local(async {
-        // ...
+        let _theme = expect_context::<Theme>();
     });
 
     leptos::task::spawn_local(async {
-        // ...
+        let _theme = expect_context::<Theme>();
     });
 
     ::leptos::task::spawn_local(async {
-        // ...
+        let _theme = expect_context::<Theme>();
     });
+
+    // Functions with the same name defined elsewhere don't trigger the lint
+    tokio::task::spawn_local(async {
+        let _theme = expect_context::<Theme>();
+    });
+
+    renamed_import::spawn();
+    glob_import::spawn();
+    scoped_imported::spawn();
+}
+
+mod tokio {
+    pub mod task {
+        pub fn spawn_local<F>(_f: F)
+        where
+            F: std::future::Future<Output = ()> + 'static,
+        {
+        }
+    }
+}
+
+mod renamed_import {
+    use super::Theme;
+    use leptos::prelude::expect_context;
+    use leptos::task::spawn_local as spawn_unscoped;
+
+    pub fn spawn() {
+        spawn_unscoped(async {
+            let _theme = expect_context::<Theme>();
+        });
+    }
 }
+
+mod glob_import {
+    use super::Theme;
+    use leptos::prelude::expect_context;
+    #[allow(clippy::wildcard_imports)]
+    use leptos::task::*;
+
+    pub fn spawn() {
+        spawn_local(async {
+            let _theme = expect_context::<Theme>();
+        });
+    }
+}
+
+mod scoped_imported {
+    use super::Theme;
+    use leptos::prelude::expect_context;
+    use leptos::task::{spawn_local, spawn_local_scoped};
+
+    pub fn spawn() {
+        spawn_local_scoped(async {
+            let _theme = expect_context::<Theme>();
+        });
+
+        spawn_local(async {
+            let _theme = expect_context::<Theme>();
+        });
+    }
+}
+
+#[derive(Clone)]
+struct Theme;
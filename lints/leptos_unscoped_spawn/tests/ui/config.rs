@@ -0,0 +1,40 @@
+use leptos::prelude::expect_context;
+use leptos::task::spawn_local;
+
+fn main() {
+    analytics::send_event();
+    prefetch();
+    not_allowed();
+}
+
+// Unscoped spawns are allowed in the modules of `allowed_in`
+mod analytics {
+    use super::Theme;
+    use leptos::prelude::expect_context;
+    use leptos::task::spawn_local;
+
+    pub fn send_event() {
+        spawn_local(async {
+            let _theme = expect_context::<Theme>();
+        });
+    }
+}
+
+// ...and in its functions, including their closures
+fn prefetch() {
+    let spawn = || {
+        spawn_local(async {
+            let _theme = expect_context::<Theme>();
+        });
+    };
+    spawn();
+}
+
+fn not_allowed() {
+    spawn_local(async {
+        let _theme = expect_context::<Theme>();
+    });
+}
+
+#[derive(Clone)]
+struct Theme;
@@ -0,0 +1,89 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use leptos::prelude::*;
+use leptos::router::hooks::use_navigate;
+use leptos::task::spawn_local;
+
+#[derive(Clone)]
+struct Theme;
+
+impl Theme {
+    fn current() -> Self {
+        expect_context()
+    }
+}
+
+trait Load {
+    fn load(&self);
+}
+
+struct Settings;
+
+impl Load for Settings {
+    fn load(&self) {
+        let _theme = use_context::<Theme>();
+    }
+}
+
+fn theme_name() -> &'static str {
+    let _theme = use_context::<Theme>();
+    "dark"
+}
+
+async fn fetch_user() {
+    leptos::task::tick().await;
+    let _theme = expect_context::<Theme>();
+}
+
+fn countdown(n: u32) -> u32 {
+    if n == 0 { 0 } else { countdown(n - 1) }
+}
+
+fn main() {
+    // Futures that don't read contexts don't trigger the lint
+    spawn_local(async {
+        let (count, set_count) = signal(0);
+        set_count.set(count.get_untracked() + countdown(3));
+    });
+
+    // Contexts read by local functions and methods
+    spawn_local(async {
+        let _name = theme_name();
+    });
+
+    spawn_local(async {
+        let _theme = Theme::current();
+    });
+
+    spawn_local(async {
+        Settings.load();
+    });
+
+    // Async functions
+    spawn_local(fetch_user());
+
+    // Hooks of other crates
+    spawn_local(async {
+        let _navigate = use_navigate();
+    });
+
+    // Closures defined outside of the future
+    let read_theme = || use_context::<Theme>();
+    spawn_local(async move {
+        let _theme = read_theme();
+    });
+
+    // Calls that can't be followed, like functions of other crates
+    spawn_local(async {
+        let _debounced = leptos_use::signal_debounced(Signal::derive(|| 1), 500.0);
+    });
+
+    let callback: Box<dyn Fn()> = Box::new(|| {});
+    spawn_local(async move {
+        callback();
+    });
+
+    let future: Pin<Box<dyn Future<Output = ()>>> = Box::pin(async {});
+    spawn_local(future);
+}
@@ -0,0 +1,32 @@
+use leptos::html::button;
+use leptos::prelude::{OnAttribute, expect_context};
+use leptos::task::spawn_local;
+use leptos::{ev, task};
+
+fn main() {
+    // Since Leptos 0.8.9, event listeners have access to contexts
+    let _button = button().on(ev::click, |_| {
+        spawn_local(async {
+            let _theme = expect_context::<Theme>();
+        });
+    });
+
+    let _button = button().on(ev::click, |_| {
+        task::spawn_local(async {
+            let _theme = expect_context::<Theme>();
+        });
+    });
+
+    // Functions declared inside a listener can be called from anywhere
+    let _button = button().on(ev::click, |_| {
+        fn load_theme() {
+            spawn_local(async {
+                let _theme = expect_context::<Theme>();
+            });
+        }
+        load_theme();
+    });
+}
+
+#[derive(Clone)]
+struct Theme;
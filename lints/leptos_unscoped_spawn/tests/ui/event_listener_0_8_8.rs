@@ -0,0 +1,30 @@
+use leptos_0_8_8::html::button;
+use leptos_0_8_8::prelude::{OnAttribute, expect_context};
+use leptos_0_8_8::task::spawn_local;
+use leptos_0_8_8::{ev, task};
+
+fn main() {
+    // Before Leptos 0.8.9, event listeners don't have access to contexts
+    let _button = button().on(ev::click, |_| {
+        spawn_local(async {
+            let _theme = expect_context::<Theme>();
+        });
+    });
+
+    let _button = button().on(ev::click, |_| {
+        let spawn = || {
+            task::spawn_local(async {
+                let _theme = expect_context::<Theme>();
+            });
+        };
+        spawn();
+    });
+
+    // Outside of event listeners contexts can be kept with a scoped spawn
+    spawn_local(async {
+        let _theme = expect_context::<Theme>();
+    });
+}
+
+#[derive(Clone)]
+struct Theme;
@@ -0,0 +1,33 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use leptos::prelude::expect_context;
+use leptos::task::{Executor, spawn_local};
+
+type BoxedFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+fn futures() -> Vec<BoxedFuture> {
+    vec![Box::pin(async {
+        // ...
+    })]
+}
+
+fn main() {
+    futures().into_iter().for_each(spawn_local);
+
+    let spawn: fn(BoxedFuture) = leptos::task::spawn_local;
+    futures().into_iter().for_each(spawn);
+
+    Executor::spawn_local(async {
+        let _theme = expect_context::<Theme>();
+    });
+
+    <Executor>::spawn_local(async {
+        let _theme = expect_context::<Theme>();
+    });
+
+    futures().into_iter().for_each(Executor::spawn_local);
+}
+
+#[derive(Clone)]
+struct Theme;
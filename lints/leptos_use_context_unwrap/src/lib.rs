@@ -0,0 +1,153 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_errors;
+extern crate rustc_hir;
+extern crate rustc_span;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{is_panic, root_macro_call};
+use clippy_utils::{get_parent_expr, peel_blocks};
+use leptos_lints_utils::mode::{Side, should_lint};
+use leptos_lints_utils::sugg::replace_fn_path;
+use leptos_lints_utils::{enclosing_call, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, QPath, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::Span;
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for calls to `leptos::prelude::use_context` whose results are
+    /// unwrapped with `.unwrap()`, `.expect(..)` or `.unwrap_or_else(|| panic!(..))`,
+    /// and recommends using `leptos::prelude::expect_context` instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `expect_context` panics too when the context is not provided, but with a
+    /// message that includes the name of the missing type and the location of the
+    /// call, which makes the error easier to track down.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// let theme = use_context::<Theme>().unwrap();
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// let theme = expect_context::<Theme>();
+    /// ```
+    ///
+    /// The lint provides a fix that can be applied automatically with
+    /// `cargo dylint --fix` for `.unwrap()`. The custom messages passed to
+    /// `.expect(..)` or `panic!` would be dropped by the fix, so it's only
+    /// suggested for them.
+    pub LEPTOS_USE_CONTEXT_UNWRAP,
+    Warn,
+    "Checks for unwrapped results of `leptos::prelude::use_context`"
+}
+
+impl<'tcx> LateLintPass<'tcx> for LeptosUseContextUnwrap {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+            return;
+        };
+
+        if !paths::USE_CONTEXT.matches(cx, def_id) || !should_lint(cx.tcx, Side::Both) {
+            return;
+        }
+
+        let Some((call, [])) = enclosing_call(cx, expr) else {
+            return;
+        };
+        let Some(unwrap) = get_parent_expr(cx, call) else {
+            return;
+        };
+        let ExprKind::MethodCall(method, receiver, args, _) = unwrap.kind else {
+            return;
+        };
+        if receiver.hir_id != call.hir_id || unwrap.span.from_expansion() {
+            return;
+        }
+        // Custom message that the fix drops
+        let message = match (method.ident.as_str(), args) {
+            ("unwrap", []) => None,
+            ("expect", [message]) => Some(message.span),
+            ("unwrap_or_else", [fallback]) => match panicking_closure(cx, fallback) {
+                Some(panic) => Some(panic),
+                None => return,
+            },
+            _ => return,
+        };
+
+        span_lint_and_then(
+            cx,
+            LEPTOS_USE_CONTEXT_UNWRAP,
+            unwrap.span,
+            format!(
+                "use of `{}` on the result of `leptos::prelude::use_context`",
+                method.ident
+            ),
+            |diag| {
+                let edits = match qpath {
+                    QPath::Resolved(None, path) => {
+                        replace_fn_path(cx, path, expr.hir_id, "expect_context")
+                    }
+                    _ => None,
+                };
+                if let Some(span) = message {
+                    diag.span_note(
+                        span,
+                        "the custom message is replaced by the one of `expect_context`",
+                    );
+                }
+                if let Some(mut edits) = edits {
+                    edits.push((unwrap.span.with_lo(call.span.hi()), String::new()));
+                    let applicability = if message.is_some() {
+                        Applicability::MaybeIncorrect
+                    } else {
+                        Applicability::MachineApplicable
+                    };
+                    diag.multipart_suggestion("use `expect_context`", edits, applicability);
+                } else {
+                    diag.help("prefer `leptos::prelude::expect_context`");
+                }
+                diag.help("for further information visit https://github.com/leptos-rs/leptos-lints/tree/main/lints/leptos_use_context_unwrap#readme");
+            },
+        );
+    }
+}
+
+/// Span of the `panic!` call of a closure without parameters that only panics,
+/// like `|| panic!("no theme")`.
+fn panicking_closure(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Span> {
+    let ExprKind::Closure(&Closure { body, .. }) = expr.kind else {
+        return None;
+    };
+    let body = cx.tcx.hir_body(body);
+    let mut value = peel_blocks(body.value);
+    // `|| { panic!("no theme"); }`
+    if let ExprKind::Block(block, _) = value.kind
+        && let ([stmt], None) = (block.stmts, block.expr)
+        && let StmtKind::Semi(inner) = stmt.kind
+    {
+        value = inner;
+    }
+    let macro_call = root_macro_call(value.span)?;
+    (body.params.is_empty() && is_panic(cx, macro_call.def_id)).then_some(macro_call.span)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui_examples() {
+        dylint_testing::ui::Test::examples(env!("CARGO_PKG_NAME")).run();
+    }
+}
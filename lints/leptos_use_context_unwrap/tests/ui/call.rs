@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+
+#[derive(Clone)]
+struct Theme;
+
+fn main() {
+    let _theme = use_context::<Theme>().unwrap();
+
+    let _theme = use_context::<Theme>().expect("the theme should be provided");
+
+    let _theme = use_context::<Theme>().unwrap_or_else(|| panic!("no theme"));
+
+    let _theme = use_context::<Theme>().unwrap_or_else(|| {
+        panic!("no theme");
+    });
+
+    let _theme: Theme = use_context().unwrap();
+
+    let _theme = leptos::prelude::use_context::<Theme>().unwrap();
+
+    // Contexts that are handled when missing don't trigger the lint
+    let _theme = use_context::<Theme>().unwrap_or(Theme);
+
+    let _theme = use_context::<Theme>().unwrap_or_else(default_theme);
+
+    if let Some(_theme) = use_context::<Theme>() {
+        // ...
+    }
+
+    let _theme = expect_context::<Theme>();
+}
+
+fn default_theme() -> Theme {
+    Theme
+}
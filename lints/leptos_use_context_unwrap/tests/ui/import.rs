@@ -0,0 +1,21 @@
+use leptos::prelude::use_context;
+use leptos::reactive::owner;
+
+#[derive(Clone)]
+struct Theme;
+
+fn main() {
+    let _theme = use_context::<Theme>().unwrap();
+
+    let _theme = owner::use_context::<Theme>().unwrap();
+
+    renamed_import::read();
+}
+
+mod renamed_import {
+    use leptos::prelude::use_context as context;
+
+    pub fn read() {
+        let _theme = context::<super::Theme>().unwrap();
+    }
+}
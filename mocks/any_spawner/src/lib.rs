@@ -0,0 +1,14 @@
+//! Mock of `any_spawner`, the executor abstraction used by Leptos.
+
+use std::future::Future;
+
+pub struct Executor;
+
+impl Executor {
+    pub fn spawn(_fut: impl Future<Output = ()> + Send + 'static) {}
+
+    pub fn spawn_local(_fut: impl Future<Output = ()> + 'static) {}
+
+    #[allow(clippy::unused_async)]
+    pub async fn tick() {}
+}
@@ -0,0 +1,193 @@
+// Mock of `leptos`, following the layout of its public API and re-exporting its
+// dependencies the same way.
+//
+// This file is also included by the mocks of older versions, so it must not
+// contain inner attributes nor out-of-line modules.
+
+pub use component::{ComponentConstructor, Props, component_props_builder, component_view};
+pub use leptos_macro::view;
+pub use reactive_graph as reactive;
+pub use tachys::html::event as ev;
+pub use {wasm_bindgen, web_sys};
+
+pub mod html {
+    pub use tachys::html::element::*;
+}
+
+pub mod component {
+    pub trait Props {
+        type Builder;
+
+        fn builder() -> Self::Builder;
+    }
+
+    pub trait ComponentConstructor<P> {
+        type Output;
+
+        fn construct(self, props: P) -> Self::Output;
+    }
+
+    impl<F, P, R> ComponentConstructor<P> for F
+    where
+        F: FnOnce(P) -> R,
+    {
+        type Output = R;
+
+        fn construct(self, props: P) -> R {
+            self(props)
+        }
+    }
+
+    pub fn component_props_builder<P: Props>(_f: &impl ComponentConstructor<P>) -> P::Builder {
+        P::builder()
+    }
+
+    pub fn component_view<P, T>(f: impl ComponentConstructor<P, Output = T>, props: P) -> T {
+        f.construct(props)
+    }
+}
+
+pub mod logging {
+    pub use leptos_dom::logging::*;
+    pub use leptos_dom::{debug_warn, error, log, warn};
+}
+
+pub mod task {
+    use std::future::Future;
+
+    pub use any_spawner::Executor;
+
+    pub fn spawn(fut: impl Future<Output = ()> + Send + 'static) {
+        Executor::spawn(fut);
+    }
+
+    pub fn spawn_local(fut: impl Future<Output = ()> + 'static) {
+        Executor::spawn_local(fut);
+    }
+
+    pub fn spawn_local_scoped(fut: impl Future<Output = ()> + 'static) {
+        Executor::spawn_local(fut);
+    }
+
+    pub async fn tick() {
+        Executor::tick().await;
+    }
+}
+
+pub mod server {
+    use std::future::Future;
+    use std::marker::PhantomData;
+
+    pub struct Resource<T> {
+        _value: PhantomData<fn() -> T>,
+    }
+
+    impl<T> Clone for Resource<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Resource<T> {}
+
+    impl<T: Send + Sync + 'static> Resource<T> {
+        pub fn new<S, Fut>(
+            _source: impl Fn() -> S + Send + Sync + 'static,
+            _fetcher: impl Fn(S) -> Fut + Send + Sync + 'static,
+        ) -> Self
+        where
+            S: Clone + Send + Sync + 'static,
+            Fut: Future<Output = T> + Send + 'static,
+        {
+            unimplemented!()
+        }
+    }
+
+    pub struct LocalResource<T> {
+        _value: PhantomData<fn() -> T>,
+    }
+
+    impl<T> Clone for LocalResource<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for LocalResource<T> {}
+
+    impl<T: 'static> LocalResource<T> {
+        pub fn new<Fut>(_fetcher: impl Fn() -> Fut + 'static) -> Self
+        where
+            Fut: Future<Output = T> + 'static,
+        {
+            unimplemented!()
+        }
+    }
+
+    pub struct ServerFnError;
+}
+
+pub mod router {
+    pub mod hooks {
+        pub struct Location;
+
+        pub struct ParamsMap;
+
+        pub struct NavigateOptions;
+
+        #[must_use]
+        pub fn use_location() -> Location {
+            unimplemented!()
+        }
+
+        pub fn use_navigate() -> impl Fn(&str, NavigateOptions) + Clone {
+            |_, _| {}
+        }
+
+        #[must_use]
+        pub fn use_params_map() -> reactive_graph::computed::Memo<ParamsMap> {
+            unimplemented!()
+        }
+
+        #[must_use]
+        pub fn use_query_map() -> reactive_graph::computed::Memo<ParamsMap> {
+            unimplemented!()
+        }
+    }
+}
+
+pub mod meta {
+    pub struct MetaContext;
+
+    pub struct ServerMetaContext;
+
+    pub fn provide_meta_context() {}
+
+    #[must_use]
+    pub fn use_head() -> MetaContext {
+        unimplemented!()
+    }
+}
+
+pub mod prelude {
+    pub use crate::logging::log;
+    pub use crate::server::{LocalResource, Resource};
+    pub use leptos_dom::helpers::*;
+    pub use leptos_macro::view;
+    pub use reactive_graph::actions::Action;
+    pub use reactive_graph::computed::Memo;
+    #[allow(deprecated)]
+    pub use reactive_graph::effect::create_effect;
+    pub use reactive_graph::effect::{Effect, RenderEffect};
+    pub use reactive_graph::graph::untrack;
+    pub use reactive_graph::owner::{
+        Owner, StoredValue, expect_context, on_cleanup, provide_context, use_context,
+    };
+    pub use reactive_graph::prelude::*;
+    pub use reactive_graph::signal::{ArcRwSignal, ReadSignal, RwSignal, WriteSignal, signal};
+    pub use reactive_graph::trigger::Trigger;
+    pub use reactive_graph::wrappers::read::Signal;
+    pub use tachys::html::attribute::global::{GlobalAttributes, OnAttribute};
+    pub use tachys::html::element::ElementChild;
+    pub use tachys::reactive_graph::Suspend;
+}
@@ -0,0 +1,2 @@
+// Mock of `leptos` 0.8.8, sharing the code of the mock of the latest version
+include!("../../leptos/src/lib.rs");
@@ -0,0 +1,124 @@
+//! Mock of `leptos_dom`, with the DOM helpers and logging macros re-exported
+//! by Leptos.
+
+#![allow(clippy::missing_errors_doc)]
+
+pub mod logging {
+    #[macro_export]
+    macro_rules! log {
+        ($($t:tt)*) => ($crate::logging::console_log(&format_args!($($t)*).to_string()))
+    }
+
+    #[macro_export]
+    macro_rules! warn {
+        ($($t:tt)*) => ($crate::logging::console_warn(&format_args!($($t)*).to_string()))
+    }
+
+    #[macro_export]
+    macro_rules! error {
+        ($($t:tt)*) => ($crate::logging::console_error(&format_args!($($t)*).to_string()))
+    }
+
+    #[macro_export]
+    macro_rules! debug_warn {
+        ($($t:tt)*) => ($crate::logging::console_debug_warn(&format_args!($($t)*).to_string()))
+    }
+
+    pub fn console_log(_s: &str) {}
+
+    pub fn console_warn(_s: &str) {}
+
+    pub fn console_error(_s: &str) {}
+
+    pub fn console_debug_warn(_s: &str) {}
+}
+
+pub mod helpers {
+    use std::time::Duration;
+
+    use tachys::html::event::EventDescriptor;
+    use wasm_bindgen::{JsCast, JsValue};
+
+    pub struct TimeoutHandle;
+
+    pub struct IntervalHandle;
+
+    pub struct AnimationFrameRequestHandle;
+
+    pub struct IdleCallbackHandle;
+
+    pub struct WindowListenerHandle;
+
+    #[must_use]
+    pub fn window() -> web_sys::Window {
+        unimplemented!()
+    }
+
+    #[must_use]
+    pub fn document() -> web_sys::Document {
+        unimplemented!()
+    }
+
+    #[allow(clippy::must_use_candidate)]
+    pub fn event_target<T: JsCast>(_event: &web_sys::Event) -> T {
+        unimplemented!()
+    }
+
+    #[allow(clippy::must_use_candidate)]
+    pub fn event_target_value<T: JsCast>(_event: &T) -> String {
+        unimplemented!()
+    }
+
+    #[must_use]
+    pub fn event_target_checked(_event: &web_sys::Event) -> bool {
+        unimplemented!()
+    }
+
+    pub fn set_timeout(_cb: impl FnOnce() + 'static, _duration: Duration) {}
+
+    pub fn set_timeout_with_handle(
+        _cb: impl FnOnce() + 'static,
+        _duration: Duration,
+    ) -> Result<TimeoutHandle, JsValue> {
+        unimplemented!()
+    }
+
+    pub fn set_interval(_cb: impl Fn() + 'static, _duration: Duration) {}
+
+    pub fn set_interval_with_handle(
+        _cb: impl Fn() + 'static,
+        _duration: Duration,
+    ) -> Result<IntervalHandle, JsValue> {
+        unimplemented!()
+    }
+
+    pub fn request_animation_frame(_cb: impl FnOnce() + 'static) {}
+
+    pub fn request_animation_frame_with_handle(
+        _cb: impl FnOnce() + 'static,
+    ) -> Result<AnimationFrameRequestHandle, JsValue> {
+        unimplemented!()
+    }
+
+    pub fn request_idle_callback(_cb: impl Fn() + 'static) {}
+
+    pub fn request_idle_callback_with_handle(
+        _cb: impl Fn() + 'static,
+    ) -> Result<IdleCallbackHandle, JsValue> {
+        unimplemented!()
+    }
+
+    pub fn window_event_listener<E: EventDescriptor + 'static>(
+        _event: E,
+        _cb: impl Fn(E::EventType) + 'static,
+    ) -> WindowListenerHandle {
+        unimplemented!()
+    }
+
+    pub fn window_event_listener_untyped(
+        _event_name: &str,
+        _cb: impl Fn(web_sys::Event) + 'static,
+    ) -> WindowListenerHandle {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,114 @@
+//! Mock of `leptos_macro`, with the `view!` macro written as a declarative
+//! macro.
+//!
+//! The generated code refers to the items of Leptos by their paths in the
+//! `leptos` crate, like the real macros do, so it can only be used by crates
+//! depending on it.
+
+/// Mock of the `view!` macro, expanding a subset of its syntax to the same
+/// builder calls: elements with attributes, event listeners like `on:click` and
+/// children, and components with props. Attribute values must be single tokens
+/// or blocks.
+#[macro_export]
+macro_rules! view {
+    ($($tokens:tt)*) => {
+        $crate::__view!([] [] $($tokens)*)
+    };
+}
+
+// Parses the nodes of `view!`, keeping the elements that are still open in a
+// stack along with the children of their parents.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __view {
+    (
+        [{ $tag:ident [$($attrs:tt)*] [$($siblings:tt)*] } $($stack:tt)*]
+        [$($children:tt)*]
+        < / $close:ident > $($rest:tt)*
+    ) => {
+        $crate::__view!(
+            [$($stack)*]
+            [$($siblings)* ($crate::__view_node!($tag [$($attrs)*] [$($children)*]))]
+            $($rest)*
+        )
+    };
+    (
+        [$($stack:tt)*]
+        [$($children:tt)*]
+        < $tag:ident $($attr:ident $(: $event:ident)? = $value:tt)* / > $($rest:tt)*
+    ) => {
+        $crate::__view!(
+            [$($stack)*]
+            [$($children)* ($crate::__view_node!($tag [$(($attr $(: $event)? = $value))*] []))]
+            $($rest)*
+        )
+    };
+    (
+        [$($stack:tt)*]
+        [$($children:tt)*]
+        < $tag:ident $($attr:ident $(: $event:ident)? = $value:tt)* > $($rest:tt)*
+    ) => {
+        $crate::__view!(
+            [{ $tag [$(($attr $(: $event)? = $value))*] [$($children)*] } $($stack)*]
+            []
+            $($rest)*
+        )
+    };
+    ([$($stack:tt)*] [$($children:tt)*] $child:block $($rest:tt)*) => {
+        $crate::__view!([$($stack)*] [$($children)* ($child)] $($rest)*)
+    };
+    ([$($stack:tt)*] [$($children:tt)*] $child:literal $($rest:tt)*) => {
+        $crate::__view!([$($stack)*] [$($children)* ($child)] $($rest)*)
+    };
+    ([] [($($node:tt)*)]) => {
+        $($node)*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __view_node {
+    (button $($node:tt)*) => { $crate::__view_element!(button $($node)*) };
+    (div $($node:tt)*) => { $crate::__view_element!(div $($node)*) };
+    (form $($node:tt)*) => { $crate::__view_element!(form $($node)*) };
+    (input $($node:tt)*) => { $crate::__view_element!(input $($node)*) };
+    (p $($node:tt)*) => { $crate::__view_element!(p $($node)*) };
+    (select $($node:tt)*) => { $crate::__view_element!(select $($node)*) };
+    (span $($node:tt)*) => { $crate::__view_element!(span $($node)*) };
+    (textarea $($node:tt)*) => { $crate::__view_element!(textarea $($node)*) };
+    ($component:ident [$(($prop:ident = $value:tt))*] []) => {
+        ::leptos::component_view(
+            &$component,
+            ::leptos::component_props_builder(&$component)
+                $(.$prop($value))*
+                .build(),
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __view_element {
+    ($tag:ident [$($attrs:tt)*] [$(($($child:tt)*))*]) => {{
+        #[allow(unused_imports)]
+        use ::leptos::prelude::{ElementChild as _, GlobalAttributes as _, OnAttribute as _};
+        $crate::__view_attributes!(::leptos::html::$tag(); $($attrs)*)
+            $(.child($($child)*))*
+    }};
+}
+
+// Adds the attributes of an element one at a time, as event listeners are added
+// with another method.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __view_attributes {
+    ($element:expr;) => {
+        $element
+    };
+    ($element:expr; (on : $event:ident = $value:tt) $($rest:tt)*) => {
+        $crate::__view_attributes!($element.on(::leptos::ev::$event, $value); $($rest)*)
+    };
+    ($element:expr; ($attr:ident = $value:tt) $($rest:tt)*) => {
+        $crate::__view_attributes!($element.$attr($value); $($rest)*)
+    };
+}
@@ -0,0 +1,9 @@
+//! Mock of `leptos-use`, with some of its utilities that aren't named like hooks,
+//! but create reactive nodes and may read contexts.
+
+use leptos::prelude::Signal;
+
+#[must_use]
+pub fn signal_debounced<T: Send + Sync + 'static>(_value: Signal<T>, _ms: f64) -> Signal<T> {
+    unimplemented!()
+}
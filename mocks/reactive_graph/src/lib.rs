@@ -0,0 +1,537 @@
+//! Mock of `reactive_graph`, the reactive system re-exported by Leptos as
+//! `leptos::reactive`.
+
+pub mod owner {
+    pub struct Owner {
+        _private: (),
+    }
+
+    impl Owner {
+        #[must_use]
+        pub fn new() -> Self {
+            unimplemented!()
+        }
+
+        #[must_use]
+        pub fn current() -> Option<Self> {
+            unimplemented!()
+        }
+
+        pub fn with<T>(&self, _fun: impl FnOnce() -> T) -> T {
+            unimplemented!()
+        }
+    }
+
+    impl Default for Owner {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub fn provide_context<T: Send + Sync + 'static>(_value: T) {}
+
+    #[must_use]
+    pub fn use_context<T: Clone + 'static>() -> Option<T> {
+        unimplemented!()
+    }
+
+    #[must_use]
+    pub fn expect_context<T: Clone + 'static>() -> T {
+        unimplemented!()
+    }
+
+    pub fn on_cleanup(_fun: impl FnOnce() + Send + Sync + 'static) {}
+
+    pub struct StoredValue<T> {
+        _value: std::marker::PhantomData<fn() -> T>,
+    }
+
+    impl<T> Clone for StoredValue<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for StoredValue<T> {}
+
+    impl<T: Send + Sync + 'static> StoredValue<T> {
+        pub fn new(_value: T) -> Self {
+            unimplemented!()
+        }
+
+        #[must_use]
+        pub fn read_value(&self) -> crate::signal::guards::ReadGuard<T> {
+            unimplemented!()
+        }
+
+        #[must_use]
+        pub fn write_value(&self) -> crate::signal::guards::UntrackedWriteGuard<T> {
+            unimplemented!()
+        }
+    }
+}
+
+pub mod signal {
+    use std::marker::PhantomData;
+
+    pub mod guards {
+        use std::marker::PhantomData;
+        use std::ops::{Deref, DerefMut};
+
+        pub struct ReadGuard<T> {
+            _value: PhantomData<T>,
+        }
+
+        // Releases the borrow of the value
+        impl<T> Drop for ReadGuard<T> {
+            fn drop(&mut self) {}
+        }
+
+        impl<T> Deref for ReadGuard<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                unimplemented!()
+            }
+        }
+
+        pub struct WriteGuard<T> {
+            _value: PhantomData<T>,
+        }
+
+        // Releases the borrow of the value
+        impl<T> Drop for WriteGuard<T> {
+            fn drop(&mut self) {}
+        }
+
+        impl<T> Deref for WriteGuard<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                unimplemented!()
+            }
+        }
+
+        impl<T> DerefMut for WriteGuard<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                unimplemented!()
+            }
+        }
+
+        pub struct UntrackedWriteGuard<T> {
+            _value: PhantomData<T>,
+        }
+
+        // Releases the borrow of the value
+        impl<T> Drop for UntrackedWriteGuard<T> {
+            fn drop(&mut self) {}
+        }
+
+        impl<T> Deref for UntrackedWriteGuard<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                unimplemented!()
+            }
+        }
+
+        impl<T> DerefMut for UntrackedWriteGuard<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                unimplemented!()
+            }
+        }
+    }
+
+    macro_rules! signal_types {
+        ($($name:ident),*) => {
+            $(
+                pub struct $name<T> {
+                    _value: PhantomData<fn() -> T>,
+                }
+
+                impl<T> Clone for $name<T> {
+                    fn clone(&self) -> Self {
+                        *self
+                    }
+                }
+
+                impl<T> Copy for $name<T> {}
+            )*
+        };
+    }
+
+    signal_types!(ReadSignal, WriteSignal, RwSignal);
+
+    pub fn signal<T: Send + Sync + 'static>(_value: T) -> (ReadSignal<T>, WriteSignal<T>) {
+        unimplemented!()
+    }
+
+    impl<T: Send + Sync + 'static> RwSignal<T> {
+        pub fn new(_value: T) -> Self {
+            unimplemented!()
+        }
+
+        pub fn new_local(_value: T) -> Self {
+            unimplemented!()
+        }
+
+        #[must_use]
+        pub fn read_only(&self) -> ReadSignal<T> {
+            unimplemented!()
+        }
+
+        #[must_use]
+        pub fn write_only(&self) -> WriteSignal<T> {
+            unimplemented!()
+        }
+
+        #[must_use]
+        pub fn split(&self) -> (ReadSignal<T>, WriteSignal<T>) {
+            unimplemented!()
+        }
+    }
+
+    pub struct ArcRwSignal<T> {
+        _value: PhantomData<fn() -> T>,
+    }
+
+    impl<T> Clone for ArcRwSignal<T> {
+        fn clone(&self) -> Self {
+            Self {
+                _value: PhantomData,
+            }
+        }
+    }
+
+    impl<T: Send + Sync + 'static> ArcRwSignal<T> {
+        pub fn new(_value: T) -> Self {
+            unimplemented!()
+        }
+    }
+}
+
+pub mod trigger {
+    pub struct Trigger {
+        _private: (),
+    }
+
+    impl Trigger {
+        #[must_use]
+        pub fn new() -> Self {
+            unimplemented!()
+        }
+
+        pub fn notify(&self) {}
+    }
+
+    impl Default for Trigger {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+pub mod computed {
+    use std::marker::PhantomData;
+
+    pub struct Memo<T> {
+        _value: PhantomData<fn() -> T>,
+    }
+
+    impl<T> Clone for Memo<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Memo<T> {}
+
+    impl<T: Send + Sync + 'static> Memo<T> {
+        pub fn new(_fun: impl Fn(Option<&T>) -> T + Send + Sync + 'static) -> Self {
+            unimplemented!()
+        }
+    }
+}
+
+pub mod graph {
+    pub fn untrack<T>(_fun: impl FnOnce() -> T) -> T {
+        unimplemented!()
+    }
+}
+
+pub mod effect {
+    pub trait EffectFunction<T, M> {
+        fn run(&mut self, prev: Option<T>) -> T;
+    }
+
+    pub struct NoParam;
+
+    pub struct SingleParam;
+
+    impl<T, F: FnMut() -> T> EffectFunction<T, NoParam> for F {
+        fn run(&mut self, _prev: Option<T>) -> T {
+            self()
+        }
+    }
+
+    impl<T, F: FnMut(Option<T>) -> T> EffectFunction<T, SingleParam> for F {
+        fn run(&mut self, prev: Option<T>) -> T {
+            self(prev)
+        }
+    }
+
+    pub struct Effect {
+        _private: (),
+    }
+
+    impl Effect {
+        pub fn new<T: 'static, M>(_fun: impl EffectFunction<T, M> + 'static) -> Self {
+            unimplemented!()
+        }
+
+        pub fn new_isomorphic<T: 'static, M>(
+            _fun: impl EffectFunction<T, M> + Send + Sync + 'static,
+        ) -> Self {
+            unimplemented!()
+        }
+
+        pub fn watch<T: 'static>(
+            _dependency_fn: impl FnMut() -> T + 'static,
+            _handler: impl FnMut(&T, Option<&T>, Option<()>) + 'static,
+            _immediate: bool,
+        ) -> Self {
+            unimplemented!()
+        }
+    }
+
+    pub struct RenderEffect<T> {
+        _value: std::marker::PhantomData<T>,
+    }
+
+    impl<T: 'static> RenderEffect<T> {
+        pub fn new(_fun: impl FnMut(Option<T>) -> T + 'static) -> Self {
+            unimplemented!()
+        }
+    }
+
+    #[deprecated = "use `Effect::new()` instead"]
+    pub fn create_effect<T: 'static>(fun: impl FnMut(Option<T>) -> T + 'static) -> Effect {
+        Effect::new(fun)
+    }
+}
+
+pub mod actions {
+    use std::future::Future;
+    use std::marker::PhantomData;
+
+    pub struct Action<I, O> {
+        _value: PhantomData<fn(I) -> O>,
+    }
+
+    impl<I, O> Clone for Action<I, O> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<I, O> Copy for Action<I, O> {}
+
+    impl<I: 'static, O: 'static> Action<I, O> {
+        pub fn new<F, Fu>(_action_fn: F) -> Self
+        where
+            F: Fn(&I) -> Fu + Send + Sync + 'static,
+            Fu: Future<Output = O> + Send + 'static,
+        {
+            unimplemented!()
+        }
+
+        pub fn new_local<F, Fu>(_action_fn: F) -> Self
+        where
+            F: Fn(&I) -> Fu + 'static,
+            Fu: Future<Output = O> + 'static,
+        {
+            unimplemented!()
+        }
+
+        pub fn dispatch(&self, _input: I) {}
+    }
+}
+
+pub mod wrappers {
+    pub mod read {
+        use std::marker::PhantomData;
+
+        pub struct Signal<T> {
+            _value: PhantomData<fn() -> T>,
+        }
+
+        impl<T> Clone for Signal<T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T> Copy for Signal<T> {}
+
+        impl<T: Send + Sync + 'static> Signal<T> {
+            pub fn derive(_derived_signal: impl Fn() -> T + Send + Sync + 'static) -> Self {
+                unimplemented!()
+            }
+        }
+    }
+}
+
+pub mod traits {
+    use crate::signal::guards::{ReadGuard, UntrackedWriteGuard, WriteGuard};
+
+    pub trait Get {
+        type Value;
+
+        fn get(&self) -> Self::Value;
+    }
+
+    pub trait GetUntracked {
+        type Value;
+
+        fn get_untracked(&self) -> Self::Value;
+    }
+
+    pub trait With {
+        type Value;
+
+        fn with<U>(&self, fun: impl FnOnce(&Self::Value) -> U) -> U;
+    }
+
+    pub trait WithUntracked {
+        type Value;
+
+        fn with_untracked<U>(&self, fun: impl FnOnce(&Self::Value) -> U) -> U;
+    }
+
+    pub trait Read {
+        type Value;
+
+        fn read(&self) -> ReadGuard<Self::Value>;
+    }
+
+    pub trait ReadUntracked {
+        type Value;
+
+        fn read_untracked(&self) -> ReadGuard<Self::Value>;
+    }
+
+    pub trait Set {
+        type Value;
+
+        fn set(&self, value: Self::Value);
+    }
+
+    pub trait Update {
+        type Value;
+
+        fn update(&self, fun: impl FnOnce(&mut Self::Value));
+    }
+
+    pub trait Write {
+        type Value;
+
+        fn write(&self) -> WriteGuard<Self::Value>;
+
+        fn write_untracked(&self) -> UntrackedWriteGuard<Self::Value>;
+    }
+
+    macro_rules! impl_read_traits {
+        ($($ty:ident),*) => {
+            $(
+                impl<T: Clone> Get for $ty<T> {
+                    type Value = T;
+
+                    fn get(&self) -> T {
+                        unimplemented!()
+                    }
+                }
+
+                impl<T: Clone> GetUntracked for $ty<T> {
+                    type Value = T;
+
+                    fn get_untracked(&self) -> T {
+                        unimplemented!()
+                    }
+                }
+
+                impl<T> With for $ty<T> {
+                    type Value = T;
+
+                    fn with<U>(&self, _fun: impl FnOnce(&T) -> U) -> U {
+                        unimplemented!()
+                    }
+                }
+
+                impl<T> WithUntracked for $ty<T> {
+                    type Value = T;
+
+                    fn with_untracked<U>(&self, _fun: impl FnOnce(&T) -> U) -> U {
+                        unimplemented!()
+                    }
+                }
+
+                impl<T> Read for $ty<T> {
+                    type Value = T;
+
+                    fn read(&self) -> ReadGuard<T> {
+                        unimplemented!()
+                    }
+                }
+
+                impl<T> ReadUntracked for $ty<T> {
+                    type Value = T;
+
+                    fn read_untracked(&self) -> ReadGuard<T> {
+                        unimplemented!()
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_write_traits {
+        ($($ty:ident),*) => {
+            $(
+                impl<T> Set for $ty<T> {
+                    type Value = T;
+
+                    fn set(&self, _value: T) {}
+                }
+
+                impl<T> Update for $ty<T> {
+                    type Value = T;
+
+                    fn update(&self, _fun: impl FnOnce(&mut T)) {}
+                }
+
+                impl<T> Write for $ty<T> {
+                    type Value = T;
+
+                    fn write(&self) -> WriteGuard<T> {
+                        unimplemented!()
+                    }
+
+                    fn write_untracked(&self) -> UntrackedWriteGuard<T> {
+                        unimplemented!()
+                    }
+                }
+            )*
+        };
+    }
+
+    use crate::computed::Memo;
+    use crate::signal::{ArcRwSignal, ReadSignal, RwSignal, WriteSignal};
+    use crate::wrappers::read::Signal;
+
+    impl_read_traits!(ReadSignal, RwSignal, ArcRwSignal, Memo, Signal);
+    impl_write_traits!(WriteSignal, RwSignal, ArcRwSignal);
+}
+
+pub mod prelude {
+    pub use crate::traits::*;
+}
@@ -0,0 +1,94 @@
+//! Mock of `reactive_stores`, with the type-erased fields of stores and the
+//! guards that they return. Fields are read and written with inherent methods,
+//! instead of the traits of `reactive_graph` that they implement.
+
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+pub struct Store<T> {
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Store<T> {}
+
+impl<T: Send + Sync + 'static> Store<T> {
+    pub fn new(_value: T) -> Self {
+        unimplemented!()
+    }
+}
+
+pub struct ArcField<T> {
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for ArcField<T> {
+    fn clone(&self) -> Self {
+        Self {
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> From<Store<T>> for ArcField<T> {
+    fn from(_store: Store<T>) -> Self {
+        unimplemented!()
+    }
+}
+
+impl<T> ArcField<T> {
+    #[must_use]
+    pub fn read(&self) -> StoreFieldReader<T> {
+        unimplemented!()
+    }
+
+    #[must_use]
+    pub fn write(&self) -> StoreFieldWriter<T> {
+        unimplemented!()
+    }
+}
+
+pub struct StoreFieldReader<T> {
+    _value: PhantomData<T>,
+}
+
+// Releases the borrow of the value
+impl<T> Drop for StoreFieldReader<T> {
+    fn drop(&mut self) {}
+}
+
+impl<T> Deref for StoreFieldReader<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unimplemented!()
+    }
+}
+
+pub struct StoreFieldWriter<T> {
+    _value: PhantomData<T>,
+}
+
+// Releases the borrow of the value
+impl<T> Drop for StoreFieldWriter<T> {
+    fn drop(&mut self) {}
+}
+
+impl<T> Deref for StoreFieldWriter<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unimplemented!()
+    }
+}
+
+impl<T> DerefMut for StoreFieldWriter<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,164 @@
+//! Mock of `tachys`, the rendering library used by Leptos, with its HTML
+//! elements, events and the `on` attribute.
+
+pub mod html {
+    pub mod element {
+        use std::marker::PhantomData;
+
+        pub struct HtmlElement<E> {
+            _element: PhantomData<E>,
+        }
+
+        macro_rules! elements {
+            ($($fn:ident => $name:ident),*) => {
+                $(
+                    pub struct $name;
+
+                    #[must_use]
+                    pub fn $fn() -> HtmlElement<$name> {
+                        HtmlElement {
+                            _element: PhantomData,
+                        }
+                    }
+                )*
+            };
+        }
+
+        pub trait ElementChild<NewChild> {
+            type Output;
+
+            fn child(self, child: NewChild) -> Self::Output;
+        }
+
+        impl<E, NewChild> ElementChild<NewChild> for HtmlElement<E> {
+            type Output = Self;
+
+            fn child(self, _child: NewChild) -> Self {
+                self
+            }
+        }
+
+        macro_rules! value_attribute {
+            ($($name:ident),*) => {
+                $(
+                    impl HtmlElement<$name> {
+                        #[must_use]
+                        pub fn value<V>(self, _value: V) -> Self {
+                            self
+                        }
+                    }
+                )*
+            };
+        }
+
+        value_attribute!(Input, Select, Textarea);
+
+        elements!(
+            button => Button,
+            div => Div,
+            form => Form,
+            input => Input,
+            p => P,
+            select => Select,
+            span => Span,
+            textarea => Textarea
+        );
+    }
+
+    pub mod event {
+        pub trait EventDescriptor: Clone {
+            type EventType;
+
+            fn name(&self) -> &'static str;
+        }
+
+        macro_rules! events {
+            ($($name:ident => $event_type:ident),*) => {
+                $(
+                    #[allow(non_camel_case_types)]
+                    #[derive(Clone, Copy)]
+                    pub struct $name;
+
+                    impl EventDescriptor for $name {
+                        type EventType = web_sys::$event_type;
+
+                        fn name(&self) -> &'static str {
+                            stringify!($name)
+                        }
+                    }
+                )*
+            };
+        }
+
+        events!(
+            blur => FocusEvent,
+            change => Event,
+            click => MouseEvent,
+            focus => FocusEvent,
+            input => Event,
+            keydown => KeyboardEvent,
+            keyup => KeyboardEvent,
+            submit => SubmitEvent
+        );
+
+        pub use web_sys::{Event, FocusEvent, InputEvent, KeyboardEvent, MouseEvent, SubmitEvent};
+    }
+
+    pub mod attribute {
+        pub mod global {
+            use crate::html::element::HtmlElement;
+            use crate::html::event::EventDescriptor;
+
+            pub trait GlobalAttributes: Sized {
+                #[must_use]
+                fn class<V>(self, _value: V) -> Self {
+                    self
+                }
+
+                #[must_use]
+                fn id<V>(self, _value: V) -> Self {
+                    self
+                }
+
+                #[must_use]
+                fn title<V>(self, _value: V) -> Self {
+                    self
+                }
+            }
+
+            impl<El> GlobalAttributes for HtmlElement<El> {}
+
+            pub trait OnAttribute<E, F> {
+                type Output;
+
+                fn on(self, event: E, cb: F) -> Self::Output;
+            }
+
+            impl<El, E, F> OnAttribute<E, F> for HtmlElement<El>
+            where
+                E: EventDescriptor + 'static,
+                F: FnMut(E::EventType) + 'static,
+            {
+                type Output = Self;
+
+                fn on(self, _event: E, _cb: F) -> Self {
+                    self
+                }
+            }
+        }
+    }
+}
+
+pub mod reactive_graph {
+    use std::future::Future;
+
+    pub struct Suspend<Fut> {
+        _fut: Fut,
+    }
+
+    impl<Fut: Future> Suspend<Fut> {
+        pub fn new(fut: Fut) -> Self {
+            Self { _fut: fut }
+        }
+    }
+}
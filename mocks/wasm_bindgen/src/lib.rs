@@ -0,0 +1,65 @@
+//! Mock of `wasm-bindgen`, with the JavaScript values, casts and closures
+//! used by Leptos apps.
+
+#[derive(Debug)]
+pub struct JsValue {
+    _private: (),
+}
+
+impl JsValue {
+    pub const NULL: Self = Self { _private: () };
+}
+
+pub trait JsCast: Sized {
+    /// # Errors
+    ///
+    /// Returns `self` back if it isn't an instance of `T`.
+    fn dyn_into<T: JsCast>(self) -> Result<T, Self> {
+        unimplemented!()
+    }
+
+    fn dyn_ref<T: JsCast>(&self) -> Option<&T> {
+        unimplemented!()
+    }
+
+    fn unchecked_into<T: JsCast>(self) -> T {
+        unimplemented!()
+    }
+
+    fn unchecked_ref<T: JsCast>(&self) -> &T {
+        unimplemented!()
+    }
+}
+
+impl JsCast for JsValue {}
+
+pub mod closure {
+    use std::marker::PhantomData;
+
+    pub struct Closure<T: ?Sized> {
+        _closure: PhantomData<Box<T>>,
+    }
+
+    impl<T: ?Sized> Closure<T> {
+        pub fn new<F: 'static>(_t: F) -> Self {
+            unimplemented!()
+        }
+
+        #[must_use]
+        #[allow(clippy::boxed_local)]
+        pub fn wrap(_data: Box<T>) -> Self {
+            unimplemented!()
+        }
+
+        pub fn once<F: 'static>(_fn_once: F) -> Self {
+            unimplemented!()
+        }
+
+        pub fn forget(self) {}
+    }
+}
+
+pub mod prelude {
+    pub use crate::closure::Closure;
+    pub use crate::{JsCast, JsValue};
+}
@@ -0,0 +1,137 @@
+//! Mock of `web-sys`, with the DOM interfaces used by Leptos apps.
+//!
+//! Like in `web-sys`, interfaces dereference to the interface they inherit from.
+
+#![allow(clippy::missing_errors_doc)]
+
+use wasm_bindgen::{JsCast, JsValue};
+
+macro_rules! interfaces {
+    ($($name:ident $(: $parent:ident)?;)*) => {
+        $(
+            #[derive(Debug)]
+            pub struct $name {
+                _private: (),
+            }
+
+            impl JsCast for $name {}
+
+            $(
+                impl std::ops::Deref for $name {
+                    type Target = $parent;
+
+                    fn deref(&self) -> &$parent {
+                        unimplemented!()
+                    }
+                }
+            )?
+        )*
+    };
+}
+
+interfaces! {
+    EventTarget;
+    Node: EventTarget;
+    Element: Node;
+    HtmlElement: Element;
+    HtmlButtonElement: HtmlElement;
+    HtmlDivElement: HtmlElement;
+    HtmlInputElement: HtmlElement;
+    HtmlSelectElement: HtmlElement;
+    HtmlTextAreaElement: HtmlElement;
+    Document: Node;
+    Window: EventTarget;
+    Event;
+    UiEvent: Event;
+    MouseEvent: UiEvent;
+    KeyboardEvent: UiEvent;
+    InputEvent: UiEvent;
+    FocusEvent: UiEvent;
+    SubmitEvent: Event;
+    CustomEvent: Event;
+    ExtendableEvent: Event;
+    FetchEvent: ExtendableEvent;
+}
+
+impl Event {
+    pub fn new(_type: &str) -> Result<Self, JsValue> {
+        unimplemented!()
+    }
+
+    #[must_use]
+    pub fn target(&self) -> Option<EventTarget> {
+        unimplemented!()
+    }
+
+    #[must_use]
+    pub fn current_target(&self) -> Option<EventTarget> {
+        unimplemented!()
+    }
+
+    pub fn prevent_default(&self) {}
+}
+
+impl MouseEvent {
+    pub fn new(_type: &str) -> Result<Self, JsValue> {
+        unimplemented!()
+    }
+}
+
+impl KeyboardEvent {
+    pub fn new(_type: &str) -> Result<Self, JsValue> {
+        unimplemented!()
+    }
+}
+
+impl InputEvent {
+    pub fn new(_type: &str) -> Result<Self, JsValue> {
+        unimplemented!()
+    }
+}
+
+impl CustomEvent {
+    pub fn new(_type: &str) -> Result<Self, JsValue> {
+        unimplemented!()
+    }
+}
+
+impl FetchEvent {
+    pub fn new(_type: &str) -> Result<Self, JsValue> {
+        unimplemented!()
+    }
+}
+
+impl HtmlInputElement {
+    #[must_use]
+    pub fn value(&self) -> String {
+        unimplemented!()
+    }
+
+    pub fn set_value(&self, _value: &str) {}
+
+    #[must_use]
+    pub fn checked(&self) -> bool {
+        unimplemented!()
+    }
+
+    pub fn set_checked(&self, _checked: bool) {}
+}
+
+impl HtmlSelectElement {
+    #[must_use]
+    pub fn value(&self) -> String {
+        unimplemented!()
+    }
+}
+
+impl HtmlTextAreaElement {
+    #[must_use]
+    pub fn value(&self) -> String {
+        unimplemented!()
+    }
+}
+
+#[must_use]
+pub fn window() -> Option<Window> {
+    unimplemented!()
+}
@@ -9,8 +9,23 @@ extern crate rustc_session;
 #[expect(clippy::no_mangle_with_rust_abi)]
 #[unsafe(no_mangle)]
 pub fn register_lints(sess: &rustc_session::Session, lint_store: &mut rustc_lint::LintStore) {
+    dylint_linting::init_config(sess);
+
     leptos_print_stdout::register_lints(sess, lint_store);
     leptos_unscoped_spawn::register_lints(sess, lint_store);
     leptos_event_target::register_lints(sess, lint_store);
     leptos_event_target_value::register_lints(sess, lint_store);
+    leptos_needless_scoped_spawn::register_lints(sess, lint_store);
+    leptos_context_after_await::register_lints(sess, lint_store);
+    leptos_use_context_unwrap::register_lints(sess, lint_store);
+    leptos_primitive_context_type::register_lints(sess, lint_store);
+    leptos_unmatched_context::register_lints(sess, lint_store);
+    leptos_context_in_detached_callback::register_lints(sess, lint_store);
+    leptos_event_target_checked::register_lints(sess, lint_store);
+    leptos_event_target_value_wrong_element::register_lints(sess, lint_store);
+    leptos_nonreactive_read_in_view::register_lints(sess, lint_store);
+    leptos_signal_read_in_component_body::register_lints(sess, lint_store);
+    leptos_guard_held_across_await::register_lints(sess, lint_store);
+    leptos_effect_for_derived_state::register_lints(sess, lint_store);
+    leptos_effect_writes_read_signal::register_lints(sess, lint_store);
 }
@@ -35,9 +35,9 @@ fn version_is_updated_in_readme() {
 
     let readme_versions = extract_versions_from_readme_content(&readme_content);
     let number_of_readme_versions = readme_versions.len();
-    if number_of_readme_versions != 2 {
+    if number_of_readme_versions != 3 {
         panic!(
-            "Expected exactly two versions in README.md, found {:?}",
+            "Expected exactly three versions in README.md, found {:?}",
             number_of_readme_versions
         );
     }
@@ -157,23 +157,13 @@ fn lints_have_help_link() {
     }
 }
 
-/// Check that lints are registered.
+/// Check that lints are registered in the lint libraries.
 ///
-/// Currently, the project only contains one lint library. If, in the future,
-/// we add more lint libraries (e.g., `all`, `recommended`, `pedantic`, `restriction`...),
-/// this test should be updated to check if lints are registered in the `all` lint library.
+/// The `all` lint library, at the root of the repository, registers every lint.
+/// Group lint libraries, at *groups/<group>*, register the lints that declare
+/// their group in the `[package.metadata.leptos_lints]` table of their *Cargo.toml*.
 #[test]
 fn lints_are_registered() {
-    let all_lints_src_lib_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("..")
-        .join("src")
-        .join("lib.rs");
-    let all_lints_src_lib_content =
-        std::fs::read_to_string(&all_lints_src_lib_path).expect("Failed to read src/lib.rs");
-    let expected_lint_names = LintDirectories::new()
-        .map(|(name, _)| name)
-        .collect::<Vec<_>>();
-
     fn extract_registered_lint_names(content: &str) -> Vec<String> {
         let mut registered_lint_names = Vec::new();
         let mut inside_register_lints_fn_body = false;
@@ -197,20 +187,47 @@ fn lints_are_registered() {
         registered_lint_names
     }
 
-    let registered_lint_names = extract_registered_lint_names(&all_lints_src_lib_content);
+    let lints = LintDirectories::new()
+        .map(|(name, path)| {
+            let group = extract_lint_group(&name, &path);
+            (name, group)
+        })
+        .collect::<Vec<_>>();
 
-    for lint_name in &registered_lint_names {
-        assert!(
-            expected_lint_names.contains(lint_name),
-            "Lint `{lint_name}` is registered in src/lib.rs, but it is not present in the lints directory",
-        );
-    }
+    let root_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let libraries = std::iter::once(("all", root_path.clone())).chain(
+        LINT_GROUPS
+            .iter()
+            .map(|group| (*group, root_path.join("groups").join(group))),
+    );
+    for (library, library_path) in libraries {
+        let src_lib_path = library_path.join("src").join("lib.rs");
+        let src_lib_content = std::fs::read_to_string(&src_lib_path)
+            .unwrap_or_else(|_| panic!("Failed to read {}", src_lib_path.display()));
+        let expected_lint_names = lints
+            .iter()
+            .filter(|(_, group)| library == "all" || group == library)
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+
+        let registered_lint_names = extract_registered_lint_names(&src_lib_content);
+
+        for lint_name in &registered_lint_names {
+            assert!(
+                expected_lint_names.contains(lint_name),
+                "Lint `{lint_name}` is registered in the `{library}` lint library, \
+                 but it is not present in the lints directory or it belongs to other group",
+            );
+        }
 
-    for expected_lint_name in &expected_lint_names {
-        assert!(
-            registered_lint_names.contains(expected_lint_name),
-            "Lint `{expected_lint_name}` is present in the lints directory, but it is not registered in src/lib.rs",
-        );
+        for expected_lint_name in &expected_lint_names {
+            assert!(
+                registered_lint_names.contains(expected_lint_name),
+                "Lint `{expected_lint_name}` is not registered in the `{library}` lint library \
+                 at {}",
+                src_lib_path.display(),
+            );
+        }
     }
 }
 
@@ -258,8 +275,8 @@ fn lints_table_is_updated() {
 
     fn generate_lints_table_content() -> String {
         let mut table_content = String::new();
-        table_content.push_str("| Rule | Description | Default level |\n");
-        table_content.push_str("| --- | --- | :-: |\n");
+        table_content.push_str("| Rule | Description | Group | Default level |\n");
+        table_content.push_str("| --- | --- | :-: | :-: |\n");
 
         let mut def_links_content = String::new();
 
@@ -285,9 +302,11 @@ fn lints_table_is_updated() {
                     panic!("Failed to extract lint level from lints/{lint_name}/src/lib.rs")
                 });
             let lowercased_lint_level = lint_level.to_lowercase();
+            let lint_group = extract_lint_group(&lint_name, &path);
 
-            let table_line =
-                format!("| [`{lint_name}`] | {lint_description} | {lowercased_lint_level} |\n");
+            let table_line = format!(
+                "| [`{lint_name}`] | {lint_description} | {lint_group} | {lowercased_lint_level} |\n"
+            );
             table_content.push_str(&table_line);
 
             let def_links_line = format!(
@@ -401,6 +420,24 @@ fn lints_descriptions_match() {
     }
 }
 
+/// Groups of lints. Each group is distributed as a lint library at *groups/<group>*.
+const LINT_GROUPS: [&str; 3] = ["recommended", "pedantic", "restriction"];
+
+/// Extract the group of a lint from the `[package.metadata.leptos_lints]` table
+/// of its *Cargo.toml*, checking that it's a known group.
+fn extract_lint_group(lint_name: &str, path: &Path) -> String {
+    let lint_cargo_toml_path = path.join("Cargo.toml");
+    let lint_cargo_toml_content = std::fs::read_to_string(&lint_cargo_toml_path)
+        .unwrap_or_else(|_| panic!("Failed to read lints/{lint_name}/Cargo.toml"));
+    let group = extract_str_field_from_cargo_toml_content(&lint_cargo_toml_content, "group =")
+        .unwrap_or_else(|| panic!("Failed to extract group from lints/{lint_name}/Cargo.toml"));
+    assert!(
+        LINT_GROUPS.contains(&group.as_str()),
+        "Lint `{lint_name}` declares an unknown group `{group}`. Expected one of {LINT_GROUPS:?}",
+    );
+    group
+}
+
 fn extract_str_field_from_cargo_toml_content(content: &str, match_: &str) -> Option<String> {
     for line in content.lines() {
         if line.starts_with(match_) {
@@ -0,0 +1,282 @@
+//! Detection of the contexts read by some code, like the future of a spawn.
+//!
+//! The code is walked with its closures, async blocks and the local functions
+//! that it calls, recursively. Contexts are read by `use_context`,
+//! `expect_context` and the hooks of other crates, named `use_*` by convention.
+//! The functions of the standard library and the core crates of Leptos are known
+//! not to read contexts otherwise. Calls of functions of other crates, and calls
+//! that can't be followed, like calls of function pointers, trait objects or
+//! generic closures, may read them.
+//!
+//! Optionally, the creation of reactive nodes and other uses of the current
+//! reactive owner, like scoped spawns, are detected too.
+
+use std::collections::HashSet;
+use std::ops::ControlFlow;
+
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{Visitor, walk_body, walk_expr};
+use rustc_hir::{BodyId, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::hir::nested_filter;
+use rustc_middle::ty::{
+    self, GenericArgsRef, Instance, InstanceKind, TyCtxt, TypeckResults, TypingEnv,
+};
+use rustc_span::Span;
+
+use crate::paths;
+
+/// How some code uses contexts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContextUse {
+    /// A context is read at the span.
+    Read(Span),
+    /// A reactive node owned by the current owner is created at the span, or the
+    /// owner is used in some other way.
+    Node(Span),
+    /// The call at the span can't be followed, so it may read contexts.
+    Unknown(Span),
+}
+
+impl ContextUse {
+    #[must_use]
+    pub const fn span(self) -> Span {
+        match self {
+            Self::Read(span) | Self::Node(span) | Self::Unknown(span) => span,
+        }
+    }
+}
+
+/// How the code of a future uses contexts, if it does.
+///
+/// Futures created by async blocks and calls are followed. Futures coming from
+/// elsewhere, like variables holding boxed futures, can't be followed.
+pub fn future_context_use<'tcx>(
+    cx: &LateContext<'tcx>,
+    future: &'tcx Expr<'tcx>,
+) -> Option<ContextUse> {
+    let mut finder = ContextUseFinder::new(cx, future, false);
+    future_use(&mut finder, future)
+}
+
+/// How the code of a future uses the reactive owner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OwnerUse {
+    /// Contexts or the owner are used, or may be used.
+    Used(ContextUse),
+    /// Contexts and the owner are not used. Functions of the core crates of Leptos
+    /// that are not known to use them are assumed not to, and the first call of
+    /// one of them, or of a trait method that can't be resolved, is kept.
+    Unused { assumed: Option<Span> },
+}
+
+/// How the code of a future uses contexts or the reactive owner.
+pub fn future_owner_use<'tcx>(cx: &LateContext<'tcx>, future: &'tcx Expr<'tcx>) -> OwnerUse {
+    let mut finder = ContextUseFinder::new(cx, future, true);
+    match future_use(&mut finder, future) {
+        Some(context_use) => OwnerUse::Used(context_use),
+        None => OwnerUse::Unused {
+            assumed: finder.assumed,
+        },
+    }
+}
+
+fn future_use<'tcx>(
+    finder: &mut ContextUseFinder<'_, 'tcx>,
+    future: &'tcx Expr<'tcx>,
+) -> Option<ContextUse> {
+    let result = match *finder.typeck_results.expr_ty(future).peel_refs().kind() {
+        ty::Coroutine(def_id, _) | ty::Closure(def_id, _) if def_id.is_local() => {
+            finder.visit_local_def(def_id.expect_local())
+        }
+        _ if matches!(future.kind, ExprKind::Call(..) | ExprKind::MethodCall(..)) => {
+            finder.visit_expr(future)
+        }
+        _ => return Some(ContextUse::Unknown(future.span)),
+    };
+    finder.found(result)
+}
+
+/// How some code, including the closures and async blocks that it contains, uses
+/// contexts, if it does.
+pub fn expr_context_use<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<ContextUse> {
+    let mut finder = ContextUseFinder::new(cx, expr, false);
+    let result = finder.visit_expr(expr);
+    finder.found(result)
+}
+
+/// Check if a function reads a context by itself, without following its body.
+pub fn is_context_read(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    paths::USE_CONTEXT.matches(cx, def_id)
+        || paths::EXPECT_CONTEXT.matches(cx, def_id)
+        || (!def_id.is_local()
+            && matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            && cx.tcx.item_name(def_id).as_str().starts_with("use_"))
+}
+
+/// Crates of the standard library, which can't read contexts.
+const STD_CRATES: &[&str] = &["alloc", "core", "std"];
+
+/// Check if an item belongs to a crate whose functions are known to read contexts
+/// only through `use_context`, `expect_context` and `use_*` hooks.
+fn is_in_known_crate(tcx: TyCtxt<'_>, def_id: DefId) -> bool {
+    paths::KNOWN_CONTEXT_CRATES.contains(&tcx.crate_name(def_id.krate).as_str())
+}
+
+struct ContextUseFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck_results: &'tcx TypeckResults<'tcx>,
+    owner: LocalDefId,
+    nodes: bool,
+    visited: HashSet<LocalDefId>,
+    unknown: Option<Span>,
+    /// First call assumed not to use contexts or the owner, without following it.
+    assumed: Option<Span>,
+}
+
+impl<'a, 'tcx> ContextUseFinder<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>, expr: &Expr<'_>, nodes: bool) -> Self {
+        Self {
+            cx,
+            typeck_results: cx.typeck_results(),
+            owner: cx.tcx.hir_enclosing_body_owner(expr.hir_id),
+            nodes,
+            visited: HashSet::new(),
+            unknown: None,
+            assumed: None,
+        }
+    }
+
+    /// Reads and nodes are reported over calls that can't be followed, as they're
+    /// sure to need the owner.
+    fn found(&self, result: ControlFlow<ContextUse>) -> Option<ContextUse> {
+        match result {
+            ControlFlow::Break(context_use) => Some(context_use),
+            ControlFlow::Continue(()) => self.unknown.map(ContextUse::Unknown),
+        }
+    }
+
+    fn visit_local_def(&mut self, def_id: LocalDefId) -> ControlFlow<ContextUse> {
+        if !self.visited.insert(def_id) {
+            return ControlFlow::Continue(());
+        }
+        match self.cx.tcx.hir_maybe_body_owned_by(def_id) {
+            Some(body) => self.visit_nested_body(body.id()),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        def_id: DefId,
+        args: GenericArgsRef<'tcx>,
+        span: Span,
+    ) -> ControlFlow<ContextUse> {
+        if is_context_read(self.cx, def_id) {
+            return ControlFlow::Break(ContextUse::Read(span));
+        }
+        if self.nodes && paths::OWNER_USES.matches(self.cx, def_id) {
+            return ControlFlow::Break(ContextUse::Node(span));
+        }
+
+        let tcx = self.cx.tcx;
+        let callee = if let Some(trait_def_id) = tcx.trait_of_item(def_id) {
+            let typing_env = TypingEnv::post_analysis(tcx, self.owner);
+            match Instance::try_resolve(tcx, typing_env, def_id, tcx.erase_regions(args)) {
+                Ok(Some(instance)) if !matches!(instance.def, InstanceKind::Virtual(..)) => {
+                    instance.def_id()
+                }
+                // Methods of local traits can be implemented by any local type
+                _ if trait_def_id.is_local() || !is_in_known_crate(tcx, trait_def_id) => {
+                    self.unknown.get_or_insert(span);
+                    return ControlFlow::Continue(());
+                }
+                _ => {
+                    self.assumed.get_or_insert(span);
+                    return ControlFlow::Continue(());
+                }
+            }
+        } else {
+            def_id
+        };
+
+        match callee.as_local() {
+            Some(local_def_id)
+                if matches!(
+                    tcx.def_kind(callee),
+                    DefKind::Fn | DefKind::AssocFn | DefKind::Closure
+                ) =>
+            {
+                self.visit_local_def(local_def_id)
+            }
+            Some(_) => ControlFlow::Continue(()),
+            // Functions of other crates can't be followed
+            None if !is_in_known_crate(tcx, callee) => {
+                self.unknown.get_or_insert(span);
+                ControlFlow::Continue(())
+            }
+            // The standard library can't use Leptos, but other known crates are only
+            // known to use the owner as listed. Futures are polled through
+            // `Future::poll`, so they're checked by the functions that create them.
+            None => {
+                if !STD_CRATES.contains(&tcx.crate_name(callee.krate).as_str())
+                    && !paths::OWNER_FREE.matches(self.cx, def_id)
+                    && !paths::OWNER_FREE.matches(self.cx, tcx.typeck_root_def_id(callee))
+                {
+                    self.assumed.get_or_insert(span);
+                }
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    fn check_call(&mut self, callee: &'tcx Expr<'tcx>, span: Span) -> ControlFlow<ContextUse> {
+        match *self.typeck_results.expr_ty(callee).peel_refs().kind() {
+            ty::FnDef(def_id, args) => self.check_fn(def_id, args, span),
+            ty::Closure(def_id, _) if def_id.is_local() => {
+                self.visit_local_def(def_id.expect_local())
+            }
+            _ => {
+                self.unknown.get_or_insert(span);
+                ControlFlow::Continue(())
+            }
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for ContextUseFinder<'_, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+    type Result = ControlFlow<ContextUse>;
+
+    fn maybe_tcx(&mut self) -> Self::MaybeTyCtxt {
+        self.cx.tcx
+    }
+
+    fn visit_nested_body(&mut self, body_id: BodyId) -> Self::Result {
+        let tcx = self.cx.tcx;
+        let typeck_results = std::mem::replace(&mut self.typeck_results, tcx.typeck_body(body_id));
+        let owner = std::mem::replace(&mut self.owner, tcx.hir_body_owner_def_id(body_id));
+        let result = walk_body(self, tcx.hir_body(body_id));
+        self.typeck_results = typeck_results;
+        self.owner = owner;
+        result
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) -> Self::Result {
+        match expr.kind {
+            ExprKind::Call(callee, _) => self.check_call(callee, expr.span)?,
+            ExprKind::MethodCall(..) => {
+                if let Some(def_id) = self.typeck_results.type_dependent_def_id(expr.hir_id) {
+                    let args = self.typeck_results.node_args(expr.hir_id);
+                    self.check_fn(def_id, args, expr.span)?;
+                }
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+}
@@ -0,0 +1,194 @@
+//! Origin of the events passed to the DOM helpers of Leptos, like
+//! `event_target`, which assume that the events have a target.
+//!
+//! The browser sets the target of the events that it dispatches to elements, so
+//! the events received by event listeners always have one. Events created by code
+//! don't have a target until they're dispatched, and some kinds of events, like
+//! the ones dispatched to service workers, never have an element as target.
+
+use clippy_utils::fn_def_id;
+use rustc_errors::Diag;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, HirId, MatchSource, Node, QPath};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::Span;
+
+use crate::{is_on_event_listener, parent_expr_through_blocks, paths};
+
+/// Where an event comes from, as far as it can be told.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventOrigin {
+    /// The event is received by an event listener, attached with an `on:`
+    /// attribute in the `view!` macro, `.on()` or `window_event_listener`.
+    Listener,
+    /// The event is of a type that the browser creates to dispatch it to
+    /// elements, like `MouseEvent` or `KeyboardEvent`, and doesn't come from a
+    /// parameter of a function or closure.
+    Dispatched,
+    /// The event is created by code at the span, like with `Event::new`.
+    Created(Span),
+    /// The event is of a type that is created by code or dispatched to workers,
+    /// like `CustomEvent` or `FetchEvent`.
+    Targetless(DefId),
+    /// The event can come from anywhere, like an event received as an argument
+    /// by a function that isn't an event listener.
+    Unknown,
+}
+
+impl EventOrigin {
+    /// Check if events of this origin are sure to have a target.
+    #[must_use]
+    pub const fn has_target(self) -> bool {
+        matches!(self, Self::Listener | Self::Dispatched)
+    }
+
+    /// Add a note explaining why an event of this origin may not have a target.
+    pub fn explain(self, cx: &LateContext<'_>, diag: &mut Diag<'_, ()>, event: &Expr<'_>) {
+        match self {
+            Self::Listener | Self::Dispatched => {}
+            Self::Created(span) => {
+                diag.span_note(
+                    span,
+                    "the event is created here, so it has no target until it's dispatched",
+                );
+            }
+            Self::Targetless(def_id) => {
+                diag.span_note(
+                    event.span,
+                    format!(
+                        "events of type `{}` are not dispatched to elements by the browser",
+                        cx.tcx.item_name(def_id)
+                    ),
+                );
+            }
+            Self::Unknown => {
+                diag.span_note(event.span, "the origin of this event is unknown");
+            }
+        }
+    }
+}
+
+/// Where the event passed to a DOM helper comes from.
+///
+/// The event is followed through references, the local variables that it's
+/// bound to and the unwrapping of the results of constructors, like
+/// `Event::new("input").unwrap()`. When its origin can't be found this way, it's
+/// told by its type, except for events received as arguments by functions that
+/// aren't event listeners, which are never known to be dispatched by the browser.
+pub fn event_origin(cx: &LateContext<'_>, event: &Expr<'_>) -> EventOrigin {
+    let mut expr = event;
+    let mut is_argument = false;
+    loop {
+        expr = match expr.kind {
+            ExprKind::AddrOf(_, _, inner) => inner,
+            ExprKind::MethodCall(method, receiver, ..)
+                if matches!(method.ident.as_str(), "unwrap" | "expect") =>
+            {
+                receiver
+            }
+            // `Event::new("input")?`
+            ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_)) => match scrutinee.kind {
+                ExprKind::Call(_, [inner]) => inner,
+                _ => break,
+            },
+            ExprKind::Call(..) if is_event_constructor(cx, expr) => {
+                return EventOrigin::Created(expr.span);
+            }
+            ExprKind::Path(QPath::Resolved(None, path)) => {
+                let Res::Local(binding) = path.res else {
+                    break;
+                };
+                match cx.tcx.parent_hir_node(binding) {
+                    Node::Param(param) if is_listener_param(cx, param.hir_id) => {
+                        return EventOrigin::Listener;
+                    }
+                    // Functions can be called with events of any origin
+                    Node::Param(_) => {
+                        is_argument = true;
+                        break;
+                    }
+                    Node::LetStmt(local) if local.pat.hir_id == binding => match local.init {
+                        Some(init) => init,
+                        None => break,
+                    },
+                    _ => break,
+                }
+            }
+            _ => break,
+        };
+    }
+
+    match *cx.typeck_results().expr_ty(event).peel_refs().kind() {
+        ty::Adt(adt, _) if !is_argument && paths::DISPATCHED_EVENT_TYPES.matches(cx, adt.did()) => {
+            EventOrigin::Dispatched
+        }
+        ty::Adt(adt, _) if paths::TARGETLESS_EVENT_TYPES.matches(cx, adt.did()) => {
+            EventOrigin::Targetless(adt.did())
+        }
+        _ => EventOrigin::Unknown,
+    }
+}
+
+/// Check if a call creates an event, like `Event::new` or
+/// `MouseEvent::new_with_mouse_event_init_dict`.
+fn is_event_constructor(cx: &LateContext<'_>, call: &Expr<'_>) -> bool {
+    fn_def_id(cx, call).is_some_and(|def_id| {
+        cx.tcx.crate_name(def_id.krate).as_str() == "web_sys"
+            && cx.tcx.item_name(def_id).as_str().starts_with("new")
+    })
+}
+
+/// The call of `.on()`, used by the `on:` attributes of `view!`, that attaches
+/// the event listener receiving an event, like `div().on(ev::click, |ev| ..)`.
+pub fn on_event_call<'tcx>(cx: &LateContext<'tcx>, event: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    let event = match event.kind {
+        ExprKind::AddrOf(_, _, inner) => inner,
+        _ => event,
+    };
+    let ExprKind::Path(QPath::Resolved(None, path)) = event.kind else {
+        return None;
+    };
+    let Res::Local(binding) = path.res else {
+        return None;
+    };
+    let Node::Param(param) = cx.tcx.parent_hir_node(binding) else {
+        return None;
+    };
+    let closure = param_closure(cx, param.hir_id)?;
+    if !is_on_event_listener(cx, closure) {
+        return None;
+    }
+    parent_expr_through_blocks(cx, closure)
+}
+
+/// Closure that has a parameter.
+fn param_closure<'tcx>(cx: &LateContext<'tcx>, param: HirId) -> Option<&'tcx Expr<'tcx>> {
+    match cx.tcx.parent_hir_node(param) {
+        Node::Expr(
+            closure @ Expr {
+                kind: ExprKind::Closure(_),
+                ..
+            },
+        ) => Some(closure),
+        _ => None,
+    }
+}
+
+/// Check if a parameter is the event received by a closure attached as an event
+/// listener.
+fn is_listener_param(cx: &LateContext<'_>, param: HirId) -> bool {
+    let Some(closure) = param_closure(cx, param) else {
+        return false;
+    };
+    if is_on_event_listener(cx, closure) {
+        return true;
+    }
+    // `window_event_listener(ev::keydown, |ev| ..)`
+    parent_expr_through_blocks(cx, closure).is_some_and(|call| match call.kind {
+        ExprKind::Call(..) => fn_def_id(cx, call)
+            .is_some_and(|def_id| paths::WINDOW_EVENT_LISTENER.matches(cx, def_id)),
+        _ => false,
+    })
+}
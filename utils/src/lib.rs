@@ -0,0 +1,299 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+// Links the compiler libraries into the unit tests
+#[cfg(test)]
+#[allow(unused_extern_crates)]
+extern crate rustc_driver;
+extern crate rustc_errors;
+extern crate rustc_hir;
+extern crate rustc_lint;
+extern crate rustc_middle;
+extern crate rustc_span;
+
+pub mod context;
+pub mod event;
+pub mod mode;
+pub mod paths;
+pub mod signal;
+pub mod sugg;
+pub mod version;
+
+use std::sync::OnceLock;
+
+use clippy_utils::macros::macro_backtrace;
+use clippy_utils::{get_parent_expr, peel_blocks};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, HirId, Item, ItemKind, Node, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::metadata::ModChild;
+use rustc_middle::ty::{self, TyCtxt};
+use rustc_span::{Span, Symbol};
+
+/// An item of the Leptos ecosystem, identified by the public paths where it can be
+/// reached from.
+///
+/// Leptos re-exports most of its API from internal crates (`reactive_graph`,
+/// `any_spawner`, `leptos_dom`...), so the paths are resolved through re-exports
+/// to the definitions that they point to. This way an item is matched no matter
+/// how it has been imported, and items with the same name defined in other crates,
+/// like `tokio::task::spawn_local`, are not.
+pub struct LeptosItem {
+    paths: &'static [&'static [&'static str]],
+    def_ids: OnceLock<Vec<DefId>>,
+}
+
+impl LeptosItem {
+    #[must_use]
+    pub const fn new(paths: &'static [&'static [&'static str]]) -> Self {
+        Self {
+            paths,
+            def_ids: OnceLock::new(),
+        }
+    }
+
+    /// Definitions that the paths of the item resolve to.
+    ///
+    /// Resolved the first time that they're requested. Only one crate is compiled
+    /// per lint session, so they're cached for the rest of it.
+    pub fn def_ids(&self, tcx: TyCtxt<'_>) -> &[DefId] {
+        self.def_ids.get_or_init(|| {
+            self.paths
+                .iter()
+                .flat_map(|path| resolve_path(tcx, path))
+                .collect()
+        })
+    }
+
+    /// Check if a definition is this item.
+    pub fn matches(&self, cx: &LateContext<'_>, def_id: DefId) -> bool {
+        self.def_ids(cx.tcx).contains(&def_id)
+    }
+}
+
+/// Resolve a path written as a string, like `web_sys::MouseEvent`, to the
+/// definitions that it points to. Used for paths coming from the configuration.
+#[must_use]
+pub fn resolve_str_path(tcx: TyCtxt<'_>, path: &str) -> Vec<DefId> {
+    resolve_path(tcx, &path.split("::").collect::<Vec<_>>())
+}
+
+/// Resolve a path starting with the name of an external crate to the definitions
+/// that it points to.
+fn resolve_path(tcx: TyCtxt<'_>, path: &[&str]) -> Vec<DefId> {
+    let Some((crate_name, segments)) = path.split_first() else {
+        return Vec::new();
+    };
+
+    let mut def_ids = tcx
+        .crates(())
+        .iter()
+        .filter(|cnum| tcx.crate_name(**cnum).as_str() == *crate_name)
+        .map(|cnum| cnum.as_def_id())
+        .collect::<Vec<_>>();
+    for segment in segments {
+        def_ids = def_ids
+            .into_iter()
+            .flat_map(|def_id| children_named(tcx, def_id, segment))
+            .collect();
+    }
+    def_ids
+}
+
+/// Children of a definition with the given name. Modules and traits are looked up
+/// for their items, including re-exports, and types for their associated items.
+fn children_named(tcx: TyCtxt<'_>, def_id: DefId, name: &str) -> Vec<DefId> {
+    match tcx.def_kind(def_id) {
+        DefKind::Mod | DefKind::Trait => module_children(tcx, def_id)
+            .iter()
+            .filter(|child| child.ident.name.as_str() == name)
+            .filter_map(|child| child.res.opt_def_id())
+            .collect(),
+        DefKind::Struct | DefKind::Enum | DefKind::Union => tcx
+            .inherent_impls(def_id)
+            .iter()
+            .flat_map(|impl_def_id| tcx.associated_item_def_ids(*impl_def_id))
+            .filter(|item_def_id| tcx.item_name(**item_def_id).as_str() == name)
+            .copied()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Items of a module, including re-exports, whether it's local or external.
+#[must_use]
+pub fn module_children(tcx: TyCtxt<'_>, def_id: DefId) -> &[ModChild] {
+    match def_id.as_local() {
+        Some(local_def_id) => tcx.module_children_local(local_def_id),
+        None => tcx.module_children(def_id),
+    }
+}
+
+/// Check if a module has an item with the given name.
+#[must_use]
+pub fn module_has_child(tcx: TyCtxt<'_>, def_id: DefId, name: &str) -> bool {
+    module_children(tcx, def_id)
+        .iter()
+        .any(|child| child.ident.name.as_str() == name)
+}
+
+/// Items visible from a node, declared in the blocks that enclose it and in its
+/// module. Items of outer modules are not included.
+pub fn items_in_scope<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> Vec<&'tcx Item<'tcx>> {
+    let mut item_ids = Vec::new();
+    for (_, node) in cx.tcx.hir_parent_iter(hir_id) {
+        match node {
+            Node::Block(block) => {
+                item_ids.extend(block.stmts.iter().filter_map(|stmt| match stmt.kind {
+                    StmtKind::Item(item_id) => Some(item_id),
+                    _ => None,
+                }));
+            }
+            Node::Item(&Item {
+                kind: ItemKind::Mod(_, module),
+                ..
+            })
+            | Node::Crate(module) => {
+                item_ids.extend(module.item_ids);
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    item_ids
+        .into_iter()
+        .map(|item_id| cx.tcx.hir_item(item_id))
+        .collect()
+}
+
+/// Call whose callee is the given expression and its arguments, if it's called.
+pub fn enclosing_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    callee: &Expr<'_>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx [Expr<'tcx>])> {
+    let call = get_parent_expr(cx, callee)?;
+    match call.kind {
+        ExprKind::Call(func, args) if func.hir_id == callee.hir_id => Some((call, args)),
+        _ => None,
+    }
+}
+
+/// Check if a node is inside one of the given modules, functions or methods,
+/// identified by their paths starting with the crate name, like `my_app::debug`.
+///
+/// Methods, including the ones of trait implementations, are identified by the
+/// path of the type that they're implemented for, like `my_app::Tracer::trace`.
+pub fn is_inside_paths(cx: &LateContext<'_>, hir_id: HirId, paths: &[String]) -> bool {
+    if paths.is_empty() {
+        return false;
+    }
+
+    let owner_path = item_path(cx.tcx, cx.tcx.hir_get_parent_item(hir_id).to_def_id());
+    paths.iter().any(|path| {
+        let segments = path.split("::").collect::<Vec<_>>();
+        segments.len() <= owner_path.len()
+            && segments
+                .iter()
+                .zip(&owner_path)
+                .all(|(segment, name)| name.as_str() == *segment)
+    })
+}
+
+/// Names of the path of an item, starting with the crate name. Implementations
+/// are replaced by the path of their self type, when it's an ADT.
+fn item_path(tcx: TyCtxt<'_>, def_id: DefId) -> Vec<Symbol> {
+    if let DefKind::Impl { .. } = tcx.def_kind(def_id)
+        && let ty::Adt(adt, _) = tcx.type_of(def_id).instantiate_identity().kind()
+    {
+        return item_path(tcx, adt.did());
+    }
+
+    let mut path = match tcx.opt_parent(def_id) {
+        Some(parent) => item_path(tcx, parent),
+        None => vec![tcx.crate_name(def_id.krate)],
+    };
+    path.extend(tcx.def_key(def_id).disambiguated_data.data.get_opt_name());
+    path
+}
+
+/// Check if the type of an expression, ignoring references, is one of the types
+/// identified by the given paths, like `web_sys::MouseEvent`.
+pub fn expr_ty_is_any_of(cx: &LateContext<'_>, expr: &Expr<'_>, paths: &[String]) -> bool {
+    if paths.is_empty() {
+        return false;
+    }
+
+    let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).peel_refs().kind() else {
+        return false;
+    };
+    paths
+        .iter()
+        .any(|path| resolve_str_path(cx.tcx, path).contains(&adt.did()))
+}
+
+/// Check if a node is inside a closure used as an event listener, attached with
+/// an `on:` attribute in the `view!` macro or with `.on()` in the builder syntax.
+///
+/// Items declared inside the listener, like functions, can be called from
+/// anywhere, so they're not part of it.
+pub fn is_in_event_listener(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    cx.tcx
+        .hir_parent_iter(hir_id)
+        .map_while(|(_, node)| match node {
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => None,
+            _ => Some(node),
+        })
+        .any(|node| match node {
+            Node::Expr(
+                closure @ Expr {
+                    kind: ExprKind::Closure(_),
+                    ..
+                },
+            ) => is_on_event_listener(cx, closure),
+            _ => false,
+        })
+}
+
+/// Check if a closure is attached as an event listener with an `on:` attribute in
+/// the `view!` macro or with `.on()` in the builder syntax.
+pub fn is_on_event_listener(cx: &LateContext<'_>, closure: &Expr<'_>) -> bool {
+    parent_expr_through_blocks(cx, closure).is_some_and(|parent| match parent.kind {
+        ExprKind::MethodCall(_, _, args, _) => {
+            // The closure may be in another body than the one being checked
+            args.iter()
+                .any(|arg| peel_blocks(arg).hir_id == closure.hir_id)
+                && cx
+                    .tcx
+                    .typeck(parent.hir_id.owner.def_id)
+                    .type_dependent_def_id(parent.hir_id)
+                    .is_some_and(|def_id| paths::ON_EVENT.matches(cx, def_id))
+        }
+        _ => false,
+    })
+}
+
+/// Parent expression of an expression, skipping the blocks that only wrap it,
+/// like the one of the value of `on:click={move |_| ..}` in `view!`.
+pub fn parent_expr_through_blocks<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+) -> Option<&'tcx Expr<'tcx>> {
+    let mut hir_id = expr.hir_id;
+    loop {
+        match cx.tcx.parent_hir_node(hir_id) {
+            Node::Block(block) if block.stmts.is_empty() => hir_id = block.hir_id,
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Block(_, None) => hir_id = parent.hir_id,
+                _ => return Some(parent),
+            },
+            _ => return None,
+        }
+    }
+}
+
+/// Check if a span comes from code generated by the `view!` macro.
+pub fn is_from_view(cx: &LateContext<'_>, span: Span) -> bool {
+    macro_backtrace(span).any(|macro_call| paths::VIEW.matches(cx, macro_call.def_id))
+}
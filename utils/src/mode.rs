@@ -0,0 +1,278 @@
+//! Mode in which the crate being linted is compiled: server-side rendering,
+//! hydration or client-side rendering.
+//!
+//! Leptos apps usually compile the same crate twice, once for the server with
+//! the `ssr` feature and once for the browser with the `hydrate` feature, so lints
+//! can skip the code that doesn't run in the current mode and avoid reporting the
+//! same findings in both compilations.
+
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+
+use rustc_middle::ty::TyCtxt;
+use rustc_span::sym;
+
+/// Mode in which a Leptos crate is compiled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Server-side rendering, with the `ssr` feature of `leptos`.
+    Ssr,
+    /// Hydration in the browser, with the `hydrate` feature of `leptos`.
+    Hydrate,
+    /// Client-side rendering, with the `csr` feature of `leptos`.
+    Csr,
+}
+
+impl Mode {
+    #[must_use]
+    pub const fn is_server(self) -> bool {
+        matches!(self, Self::Ssr)
+    }
+
+    #[must_use]
+    pub const fn is_client(self) -> bool {
+        matches!(self, Self::Hydrate | Self::Csr)
+    }
+}
+
+/// Side where the code checked by a lint runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// Code that only runs in the browser, like event handlers.
+    Client,
+    /// Code that only runs in the server, like the bodies of server functions.
+    Server,
+    /// Code that runs in the server and in the browser, like components.
+    Both,
+}
+
+/// What is known about the compilation of the crate being linted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Compilation {
+    /// Mode of the current compilation, if it can be detected.
+    mode: Option<Mode>,
+    /// Whether the crate is also compiled for the other side, as apps with
+    /// server-side rendering and hydration do.
+    dual: bool,
+}
+
+/// Mode of the current compilation, if it can be detected.
+///
+/// It's detected from the features of `leptos` enabled by the crate, the
+/// `lib-features` and `bin-features` of the `[package.metadata.leptos]` table used
+/// by cargo-leptos, and the target architecture. Client-side rendering is assumed
+/// for `wasm32` targets without any other hint.
+#[must_use]
+pub fn mode(tcx: TyCtxt<'_>) -> Option<Mode> {
+    compilation(tcx).mode
+}
+
+/// Check if a lint checking code that runs on the given side should report its
+/// findings in the current compilation.
+///
+/// Lints for one side are skipped in compilations for the other one. Lints for
+/// both sides are skipped in the client compilation of crates that are also
+/// compiled for the server, so their findings are reported only once. When the
+/// mode is unknown, all the lints report.
+#[must_use]
+pub fn should_lint(tcx: TyCtxt<'_>, side: Side) -> bool {
+    let Compilation { mode, dual } = compilation(tcx);
+    let Some(mode) = mode else {
+        return true;
+    };
+    match side {
+        Side::Client => mode.is_client(),
+        Side::Server => mode.is_server(),
+        Side::Both => mode.is_server() || !dual,
+    }
+}
+
+fn compilation(tcx: TyCtxt<'_>) -> Compilation {
+    static COMPILATION: OnceLock<Compilation> = OnceLock::new();
+
+    *COMPILATION.get_or_init(|| {
+        let manifest = std::env::var("CARGO_MANIFEST_DIR")
+            .ok()
+            .and_then(|dir| std::fs::read_to_string(format!("{dir}/Cargo.toml")).ok())
+            .and_then(|content| content.parse::<toml::Table>().ok())
+            .unwrap_or_default();
+        let enabled_features = tcx
+            .sess
+            .psess
+            .config
+            .iter()
+            .filter(|(name, _)| *name == sym::feature)
+            .filter_map(|(_, value)| value.map(|value| value.to_string()))
+            .collect::<Vec<_>>();
+        detect(
+            &manifest,
+            &enabled_features,
+            tcx.sess.target.arch == "wasm32",
+        )
+    })
+}
+
+/// Detect the compilation from the manifest of the crate, its enabled features
+/// and whether the target is `wasm32`.
+///
+/// Only the `leptos` dependency of the `[dependencies]` table is taken into
+/// account, target-specific dependencies are ignored.
+fn detect(manifest: &toml::Table, enabled_features: &[String], wasm32: bool) -> Compilation {
+    let crate_features = manifest.get("features").and_then(toml::Value::as_table);
+    let leptos_features_of = |feature: &str| {
+        crate_features
+            .and_then(|features| features.get(feature))
+            .and_then(toml::Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(toml::Value::as_str)
+            .filter_map(|activated| {
+                activated
+                    .strip_prefix("leptos/")
+                    .or_else(|| activated.strip_prefix("leptos?/"))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut leptos_features = manifest
+        .get("dependencies")
+        .and_then(|dependencies| dependencies.get("leptos")?.get("features")?.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(toml::Value::as_str)
+        .collect::<BTreeSet<_>>();
+    for feature in enabled_features {
+        leptos_features.extend(leptos_features_of(feature));
+    }
+
+    let cargo_leptos_features = |key: &str| {
+        manifest
+            .get("package")
+            .and_then(|package| package.get("metadata")?.get("leptos")?.get(key)?.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(toml::Value::as_str)
+            .collect::<Vec<_>>()
+    };
+    let lib_features = cargo_leptos_features("lib-features");
+    let bin_features = cargo_leptos_features("bin-features");
+    let is_enabled = |feature: &&str| enabled_features.iter().any(|enabled| enabled == feature);
+
+    let mode = if leptos_features.contains("ssr") {
+        Some(Mode::Ssr)
+    } else if leptos_features.contains("hydrate") {
+        Some(Mode::Hydrate)
+    } else if leptos_features.contains("csr") {
+        Some(Mode::Csr)
+    } else if bin_features.iter().any(is_enabled) {
+        Some(Mode::Ssr)
+    } else if lib_features.iter().any(is_enabled) {
+        Some(Mode::Hydrate)
+    } else if wasm32 {
+        Some(Mode::Csr)
+    } else {
+        None
+    };
+
+    let activates = |leptos_feature: &str| {
+        crate_features.is_some_and(|features| {
+            features
+                .keys()
+                .any(|feature| leptos_features_of(feature).contains(&leptos_feature))
+        })
+    };
+    let dual = (!lib_features.is_empty() && !bin_features.is_empty())
+        || (activates("ssr") && (activates("hydrate") || activates("csr")));
+
+    Compilation { mode, dual }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Compilation, Mode, detect};
+
+    fn detect_str(manifest: &str, enabled_features: &[&str], wasm32: bool) -> Compilation {
+        let enabled_features = enabled_features
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        detect(&manifest.parse().unwrap(), &enabled_features, wasm32)
+    }
+
+    const CARGO_LEPTOS_MANIFEST: &str = r#"
+        [dependencies]
+        leptos = { version = "0.8" }
+
+        [features]
+        hydrate = ["leptos/hydrate"]
+        ssr = ["leptos/ssr", "dep:axum"]
+
+        [package.metadata.leptos]
+        lib-features = ["hydrate"]
+        bin-features = ["ssr"]
+    "#;
+
+    #[test]
+    fn mode_from_enabled_features() {
+        let ssr = detect_str(CARGO_LEPTOS_MANIFEST, &["ssr"], false);
+        assert_eq!(
+            ssr,
+            Compilation {
+                mode: Some(Mode::Ssr),
+                dual: true
+            }
+        );
+
+        let hydrate = detect_str(CARGO_LEPTOS_MANIFEST, &["hydrate"], true);
+        assert_eq!(
+            hydrate,
+            Compilation {
+                mode: Some(Mode::Hydrate),
+                dual: true
+            }
+        );
+    }
+
+    #[test]
+    fn mode_from_cargo_leptos_metadata() {
+        let manifest = r#"
+            [features]
+            server = []
+            client = []
+
+            [package.metadata.leptos]
+            lib-features = ["client"]
+            bin-features = ["server"]
+        "#;
+
+        assert_eq!(
+            detect_str(manifest, &["server"], false).mode,
+            Some(Mode::Ssr)
+        );
+        assert_eq!(
+            detect_str(manifest, &["client"], true).mode,
+            Some(Mode::Hydrate)
+        );
+    }
+
+    #[test]
+    fn mode_from_leptos_dependency() {
+        let csr = r#"
+            [dependencies]
+            leptos = { version = "0.8", features = ["csr"] }
+        "#;
+        assert_eq!(
+            detect_str(csr, &[], true),
+            Compilation {
+                mode: Some(Mode::Csr),
+                dual: false
+            }
+        );
+    }
+
+    #[test]
+    fn mode_from_target() {
+        assert_eq!(detect_str("", &[], true).mode, Some(Mode::Csr));
+        assert_eq!(detect_str("", &[], false).mode, None);
+    }
+}
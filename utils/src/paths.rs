@@ -0,0 +1,317 @@
+//! Leptos items checked by the lints.
+//!
+//! Each item lists the public paths where users can import it from. Items are
+//! usually reachable from several paths, but all of them resolve to the same
+//! definition, so listing alternative paths is only needed for crates that can be
+//! used without depending on `leptos` directly.
+
+use crate::LeptosItem;
+
+/// `leptos::task::spawn_local`
+pub static SPAWN_LOCAL: LeptosItem = LeptosItem::new(&[
+    &["leptos", "task", "spawn_local"],
+    &["leptos", "task", "Executor", "spawn_local"],
+    &["any_spawner", "Executor", "spawn_local"],
+]);
+
+/// `leptos::prelude::event_target`
+pub static EVENT_TARGET: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "event_target"],
+    &["leptos_dom", "helpers", "event_target"],
+]);
+
+/// `leptos::prelude::event_target_value`
+pub static EVENT_TARGET_VALUE: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "event_target_value"],
+    &["leptos_dom", "helpers", "event_target_value"],
+]);
+
+/// `leptos::prelude::event_target_checked`
+pub static EVENT_TARGET_CHECKED: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "event_target_checked"],
+    &["leptos_dom", "helpers", "event_target_checked"],
+]);
+
+/// `leptos::prelude::window_event_listener` and its untyped variant
+pub static WINDOW_EVENT_LISTENER: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "window_event_listener"],
+    &["leptos", "prelude", "window_event_listener_untyped"],
+]);
+
+/// Types of events that the browser creates to dispatch them to elements, so
+/// they have a target unless they're created by code
+pub static DISPATCHED_EVENT_TYPES: LeptosItem = LeptosItem::new(&[
+    &["web_sys", "UiEvent"],
+    &["web_sys", "MouseEvent"],
+    &["web_sys", "PointerEvent"],
+    &["web_sys", "WheelEvent"],
+    &["web_sys", "DragEvent"],
+    &["web_sys", "KeyboardEvent"],
+    &["web_sys", "InputEvent"],
+    &["web_sys", "FocusEvent"],
+    &["web_sys", "CompositionEvent"],
+    &["web_sys", "TouchEvent"],
+    &["web_sys", "SubmitEvent"],
+    &["web_sys", "AnimationEvent"],
+    &["web_sys", "TransitionEvent"],
+    &["web_sys", "ClipboardEvent"],
+]);
+
+/// Types of events that are created by code or dispatched to workers, so they
+/// may not have a target
+pub static TARGETLESS_EVENT_TYPES: LeptosItem = LeptosItem::new(&[
+    &["web_sys", "CustomEvent"],
+    &["web_sys", "ExtendableEvent"],
+    &["web_sys", "FetchEvent"],
+]);
+
+/// `leptos::component::Props`, implemented by the props that `#[component]`
+/// generates for each component
+pub static COMPONENT_PROPS: LeptosItem = LeptosItem::new(&[&["leptos", "component", "Props"]]);
+
+/// `leptos::logging::log!`
+pub static LOG: LeptosItem =
+    LeptosItem::new(&[&["leptos", "logging", "log"], &["leptos_dom", "log"]]);
+
+/// `leptos::view!`
+pub static VIEW: LeptosItem = LeptosItem::new(&[&["leptos", "view"], &["leptos_macro", "view"]]);
+
+/// `leptos::prelude::OnAttribute::on`, used by `on:` attributes of `view!`
+pub static ON_EVENT: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "OnAttribute", "on"],
+    &["tachys", "html", "attribute", "global", "OnAttribute", "on"],
+]);
+
+/// `leptos::html::HtmlElement`, whose first generic argument is the kind of
+/// element
+pub static HTML_ELEMENT: LeptosItem = LeptosItem::new(&[
+    &["leptos", "html", "HtmlElement"],
+    &["tachys", "html", "element", "HtmlElement"],
+]);
+
+/// Types of views that aren't elements, which may render any element
+pub static VIEW_TYPES: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "AnyView"],
+    &["leptos", "prelude", "View"],
+    &["leptos", "prelude", "Fragment"],
+]);
+
+/// `leptos::prelude::ElementChild::child`, used by the children of elements of
+/// `view!`
+pub static ELEMENT_CHILD: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "ElementChild", "child"],
+    &["tachys", "html", "element", "ElementChild", "child"],
+]);
+
+/// `leptos::prelude::use_context`
+pub static USE_CONTEXT: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "use_context"],
+    &["reactive_graph", "owner", "use_context"],
+]);
+
+/// `leptos::prelude::expect_context`
+pub static EXPECT_CONTEXT: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "expect_context"],
+    &["reactive_graph", "owner", "expect_context"],
+]);
+
+/// `leptos::prelude::provide_context`
+pub static PROVIDE_CONTEXT: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "provide_context"],
+    &["reactive_graph", "owner", "provide_context"],
+]);
+
+/// Types of signals, memos and their wrappers, whose first generic argument is
+/// the type of the value
+pub static SIGNAL_TYPES: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "ReadSignal"],
+    &["leptos", "prelude", "WriteSignal"],
+    &["leptos", "prelude", "RwSignal"],
+    &["leptos", "prelude", "ArcReadSignal"],
+    &["leptos", "prelude", "ArcWriteSignal"],
+    &["leptos", "prelude", "ArcRwSignal"],
+    &["leptos", "prelude", "Memo"],
+    &["leptos", "prelude", "ArcMemo"],
+    &["leptos", "prelude", "Signal"],
+    &["leptos", "prelude", "ArcSignal"],
+]);
+
+/// Methods that read the value of a signal, like `leptos::prelude::Get::get`
+pub static SIGNAL_READS: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "Get", "get"],
+    &["leptos", "prelude", "GetUntracked", "get_untracked"],
+    &["leptos", "prelude", "With", "with"],
+    &["leptos", "prelude", "Read", "read"],
+]);
+
+/// Methods that read the value of a signal and subscribe to it, like
+/// `leptos::prelude::Get::get`
+pub static TRACKED_SIGNAL_READS: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "Get", "get"],
+    &["leptos", "prelude", "With", "with"],
+    &["leptos", "prelude", "Read", "read"],
+]);
+
+/// Methods that write the value of a signal and notify its subscribers, like
+/// `leptos::prelude::Set::set`
+pub static SIGNAL_WRITES: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "Set", "set"],
+    &["leptos", "prelude", "Update", "update"],
+    &["leptos", "prelude", "Write", "write"],
+]);
+
+/// `leptos::prelude::Set::set`, which replaces the value of a signal
+pub static SIGNAL_SET: LeptosItem = LeptosItem::new(&[&["leptos", "prelude", "Set", "set"]]);
+
+/// Functions that create a signal split in a read and a write half, like
+/// `leptos::prelude::signal`
+pub static SPLIT_SIGNALS: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "signal"],
+    &["leptos", "prelude", "RwSignal", "split"],
+]);
+
+/// Functions that create effects running a closure each time that the signals
+/// that it reads change
+pub static EFFECTS: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "Effect", "new"],
+    &["leptos", "prelude", "Effect", "new_isomorphic"],
+    &["leptos", "prelude", "RenderEffect", "new"],
+    &["leptos", "prelude", "create_effect"],
+]);
+
+/// `leptos::prelude::Effect::watch`, whose effect only tracks the signals read
+/// by its first closure
+pub static EFFECT_WATCH: LeptosItem = LeptosItem::new(&[&["leptos", "prelude", "Effect", "watch"]]);
+
+/// Functions that run a closure without tracking the signals that it reads, like
+/// `leptos::prelude::untrack`
+pub static UNTRACK: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "untrack"],
+    &["leptos", "prelude", "untrack_with_diagnostics"],
+]);
+
+/// Guards returned by `.read()` and `.write()` of signals, stored values and
+/// stores, which hold a borrow of the value while they're alive. Stores return
+/// the guards of signals, except for their type-erased and keyed fields
+pub static SIGNAL_GUARD_TYPES: LeptosItem = LeptosItem::new(&[
+    &["leptos", "reactive", "signal", "guards", "ReadGuard"],
+    &["leptos", "reactive", "signal", "guards", "WriteGuard"],
+    &[
+        "leptos",
+        "reactive",
+        "signal",
+        "guards",
+        "UntrackedWriteGuard",
+    ],
+    &["reactive_graph", "signal", "guards", "ReadGuard"],
+    &["reactive_graph", "signal", "guards", "WriteGuard"],
+    &["reactive_graph", "signal", "guards", "UntrackedWriteGuard"],
+    &["reactive_stores", "StoreFieldReader"],
+    &["reactive_stores", "StoreFieldWriter"],
+    &["reactive_stores", "KeyedSubfieldWriteGuard"],
+]);
+
+/// `leptos::task::spawn_local_scoped`
+pub static SPAWN_LOCAL_SCOPED: LeptosItem =
+    LeptosItem::new(&[&["leptos", "task", "spawn_local_scoped"]]);
+
+/// `leptos::prelude::Owner::with`, which runs a function with an owner
+pub static OWNER_WITH: LeptosItem = LeptosItem::new(&[&["leptos", "prelude", "Owner", "with"]]);
+
+/// Functions that run a callback later, from the browser's event loop, outside
+/// of the reactive owner that registered it
+pub static DETACHED_CALLBACKS: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "set_timeout"],
+    &["leptos", "prelude", "set_timeout_with_handle"],
+    &["leptos", "prelude", "set_interval"],
+    &["leptos", "prelude", "set_interval_with_handle"],
+    &["leptos", "prelude", "request_animation_frame"],
+    &["leptos", "prelude", "request_animation_frame_with_handle"],
+    &["leptos", "prelude", "request_idle_callback"],
+    &["leptos", "prelude", "request_idle_callback_with_handle"],
+    &["leptos", "prelude", "window_event_listener"],
+    &["leptos", "prelude", "window_event_listener_untyped"],
+]);
+
+/// Constructors of `wasm_bindgen::closure::Closure`, whose closures are called by
+/// JavaScript code
+pub static WASM_BINDGEN_CLOSURES: LeptosItem = LeptosItem::new(&[
+    &["wasm_bindgen", "closure", "Closure", "new"],
+    &["wasm_bindgen", "closure", "Closure", "once"],
+    &["wasm_bindgen", "closure", "Closure", "wrap"],
+]);
+
+/// Crates whose functions are known to read contexts only through the functions of
+/// `USE_CONTEXT` and `EXPECT_CONTEXT` and the `use_*` hooks, if at all: the
+/// standard library, the core crates of Leptos and the bindings to the browser
+/// APIs. Functions of other crates may read contexts in any way.
+pub const KNOWN_CONTEXT_CRATES: &[&str] = &[
+    "alloc",
+    "any_spawner",
+    "core",
+    "js_sys",
+    "leptos",
+    "leptos_dom",
+    "leptos_macro",
+    "leptos_server",
+    "reactive_graph",
+    "reactive_stores",
+    "server_fn",
+    "std",
+    "tachys",
+    "wasm_bindgen",
+    "wasm_bindgen_futures",
+    "web_sys",
+];
+
+/// Functions that create reactive nodes owned by the current reactive owner, or
+/// use the owner in some other way
+pub static OWNER_USES: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "signal"],
+    &["leptos", "prelude", "signal_local"],
+    &["leptos", "prelude", "arc_signal"],
+    &["leptos", "prelude", "RwSignal", "new"],
+    &["leptos", "prelude", "RwSignal", "new_local"],
+    &["leptos", "prelude", "ArcRwSignal", "new"],
+    &["leptos", "prelude", "Memo", "new"],
+    &["leptos", "prelude", "Memo", "new_owning"],
+    &["leptos", "prelude", "ArcMemo", "new"],
+    &["leptos", "prelude", "Signal", "derive"],
+    &["leptos", "prelude", "StoredValue", "new"],
+    &["leptos", "prelude", "StoredValue", "new_local"],
+    &["leptos", "prelude", "Trigger", "new"],
+    &["leptos", "prelude", "ArcTrigger", "new"],
+    &["leptos", "prelude", "Effect", "new"],
+    &["leptos", "prelude", "Effect", "new_isomorphic"],
+    &["leptos", "prelude", "Effect", "new_sync"],
+    &["leptos", "prelude", "Effect", "watch"],
+    &["leptos", "prelude", "RenderEffect", "new"],
+    &["leptos", "prelude", "ImmediateEffect", "new"],
+    &["leptos", "prelude", "Action", "new"],
+    &["leptos", "prelude", "Action", "new_local"],
+    &["leptos", "prelude", "ServerAction", "new"],
+    &["leptos", "prelude", "Resource", "new"],
+    &["leptos", "prelude", "Resource", "new_blocking"],
+    &["leptos", "prelude", "LocalResource", "new"],
+    &["leptos", "prelude", "OnceResource", "new"],
+    &["leptos", "prelude", "Owner", "current"],
+    &["leptos", "prelude", "provide_context"],
+    &["leptos", "prelude", "on_cleanup"],
+    &["leptos", "task", "spawn_local_scoped"],
+]);
+
+/// Functions of Leptos known not to read contexts nor use the reactive owner,
+/// like the methods that read and write signals
+pub static OWNER_FREE: LeptosItem = LeptosItem::new(&[
+    &["leptos", "prelude", "Get", "get"],
+    &["leptos", "prelude", "GetUntracked", "get_untracked"],
+    &["leptos", "prelude", "With", "with"],
+    &["leptos", "prelude", "WithUntracked", "with_untracked"],
+    &["leptos", "prelude", "Read", "read"],
+    &["leptos", "prelude", "ReadUntracked", "read_untracked"],
+    &["leptos", "prelude", "Set", "set"],
+    &["leptos", "prelude", "Update", "update"],
+    &["leptos", "prelude", "Write", "write"],
+    &["leptos", "task", "spawn_local"],
+    &["leptos", "task", "tick"],
+]);
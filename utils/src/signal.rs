@@ -0,0 +1,136 @@
+//! Identity of the signals accessed by some code, like the ones read and written
+//! by an effect.
+//!
+//! Signals are told apart by the variables and fields that hold them, so the same
+//! signal held by two variables is seen as two signals. The read and write halves
+//! of signals created with `signal()` or `RwSignal::split` and bound to a tuple
+//! pattern are known to be the same signal.
+
+use std::ops::ControlFlow;
+
+use clippy_utils::visitors::{Descend, for_each_expr};
+use clippy_utils::{fn_def_id, path_to_local};
+use rustc_hir::{ClosureKind, Expr, ExprKind, HirId, LetStmt, Node, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::Symbol;
+
+use crate::{LeptosItem, is_on_event_listener, parent_expr_through_blocks, paths};
+
+/// A signal, identified by the variable that holds it and the fields accessed
+/// from it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignalPlace {
+    /// The variable, or the call creating both halves of a split signal.
+    pub root: HirId,
+    pub fields: Vec<Symbol>,
+}
+
+/// Place of the signal that an expression evaluates to, like `count` or
+/// `form.name`.
+pub fn signal_place(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> Option<SignalPlace> {
+    let mut fields = Vec::new();
+    while let ExprKind::Field(base, field) = expr.kind {
+        fields.push(field.name);
+        expr = base;
+    }
+    fields.reverse();
+
+    let binding = path_to_local(expr)?;
+    let root = if fields.is_empty() {
+        split_signal(cx, binding).map_or(binding, |(_, init)| init.hir_id)
+    } else {
+        binding
+    };
+    Some(SignalPlace { root, fields })
+}
+
+/// Signals accessed by the methods of an item in an expression, with the calls
+/// that access them, in the order that they're written.
+pub fn signal_accesses<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    methods: &LeptosItem,
+) -> Vec<(SignalPlace, &'tcx Expr<'tcx>)> {
+    accesses(cx, expr, methods, false)
+}
+
+/// Signals read and tracked by the code running an expression, with the calls
+/// that read them, in the order that they're written.
+///
+/// The reads of closures and async blocks that don't run with the expression, or
+/// run without tracking, are not tracked by it, like the ones of `untrack`,
+/// `spawn_local` or event listeners.
+pub fn tracked_signal_reads<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Vec<(SignalPlace, &'tcx Expr<'tcx>)> {
+    accesses(cx, expr, &paths::TRACKED_SIGNAL_READS, true)
+}
+
+fn accesses<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    methods: &LeptosItem,
+    tracked: bool,
+) -> Vec<(SignalPlace, &'tcx Expr<'tcx>)> {
+    let mut accesses = Vec::new();
+    for_each_expr(cx, expr, |expr| {
+        if tracked && is_untracked_scope(cx, expr) {
+            return ControlFlow::<(), _>::Continue(Descend::No);
+        }
+        if let ExprKind::MethodCall(_, receiver, ..) = expr.kind
+            && !expr.span.from_expansion()
+            && fn_def_id(cx, expr).is_some_and(|def_id| methods.matches(cx, def_id))
+            && let Some(place) = signal_place(cx, receiver)
+        {
+            accesses.push((place, expr));
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+    accesses
+}
+
+/// Check if an expression is a closure or async block whose code doesn't run
+/// tracked with the code around it: async blocks, which run when they're polled,
+/// event listeners, and closures passed to `untrack`, to other effects or to
+/// functions that call them later, like `set_timeout`.
+fn is_untracked_scope(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let ExprKind::Closure(closure) = expr.kind else {
+        return false;
+    };
+    if !matches!(closure.kind, ClosureKind::Closure) || is_on_event_listener(cx, expr) {
+        return true;
+    }
+    parent_expr_through_blocks(cx, expr).is_some_and(|call| {
+        matches!(call.kind, ExprKind::Call(..))
+            && fn_def_id(cx, call).is_some_and(|def_id| {
+                paths::UNTRACK.matches(cx, def_id)
+                    || paths::EFFECTS.matches(cx, def_id)
+                    || paths::DETACHED_CALLBACKS.matches(cx, def_id)
+            })
+    })
+}
+
+/// Tuple pattern and call creating the split signal whose half is bound to a
+/// variable, like `(count, set_count)` and `signal(0)` in
+/// `let (count, set_count) = signal(0);`.
+pub fn split_signal<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding: HirId,
+) -> Option<(&'tcx Pat<'tcx>, &'tcx Expr<'tcx>)> {
+    let Node::Pat(tuple) = cx.tcx.parent_hir_node(binding) else {
+        return None;
+    };
+    if !matches!(tuple.kind, PatKind::Tuple(..)) {
+        return None;
+    }
+    let Node::LetStmt(LetStmt {
+        init: Some(init), ..
+    }) = cx.tcx.parent_hir_node(tuple.hir_id)
+    else {
+        return None;
+    };
+    fn_def_id(cx, init)
+        .is_some_and(|def_id| paths::SPLIT_SIGNALS.matches(cx, def_id))
+        .then_some((tuple, *init))
+}
@@ -0,0 +1,145 @@
+//! Building blocks for the suggestions of the lints.
+
+use clippy_utils::source::snippet_with_applicability;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, ItemKind, Path, UseKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::Span;
+use rustc_span::symbol::kw;
+
+use crate::{items_in_scope, module_has_child};
+
+/// Edits that replace the function referenced by `path` with another function
+/// named `replacement`, defined in the same module, keeping the qualification
+/// style of the path.
+///
+/// `task::spawn_local` becomes `task::spawn_local_scoped`,
+/// `::leptos::task::spawn_local` becomes `::leptos::task::spawn_local_scoped` and
+/// so on. When the function is referenced by a name imported with a `use` item,
+/// an import of the replacement is added after it, unless it's already imported.
+///
+/// Returns `None` when the replacement can't be found next to the function, so
+/// the edits can always be applied automatically.
+pub fn replace_fn_path(
+    cx: &LateContext<'_>,
+    path: &Path<'_>,
+    hir_id: HirId,
+    replacement: &str,
+) -> Option<Vec<(Span, String)>> {
+    let [qualifier @ .., last] = path.segments else {
+        return None;
+    };
+    if path.span.from_expansion() {
+        return None;
+    }
+
+    let source_map = cx.sess().source_map();
+    let prefix = source_map
+        .span_to_snippet(path.span.until(last.ident.span))
+        .ok()?;
+    let generic_args = source_map
+        .span_to_snippet(last.ident.span.between(path.span.shrink_to_hi()))
+        .ok()?;
+    let mut edits = vec![(path.span, format!("{prefix}{replacement}{generic_args}"))];
+
+    if let Some(module) = qualifier.last() {
+        // `task::spawn_local`, `leptos::task::spawn_local`...
+        let Res::Def(DefKind::Mod, module_def_id) = module.res else {
+            return None;
+        };
+        if !module_has_child(cx.tcx, module_def_id, replacement) {
+            return None;
+        }
+        return Some(edits);
+    }
+
+    // `spawn_local`, imported in the current scope
+    let items = items_in_scope(cx, hir_id);
+    let import = items.iter().find_map(|item| match item.kind {
+        ItemKind::Use(use_path, UseKind::Single(ident)) if ident.name == last.ident.name => {
+            Some((item, use_path))
+        }
+        _ => None,
+    });
+
+    let Some((import_item, import_path)) = import else {
+        // Imported with a glob, like `use leptos::task::*`
+        let imported_by_glob = items.iter().any(|item| match item.kind {
+            ItemKind::Use(use_path, UseKind::Glob) => use_path.res.iter().any(|res| {
+                matches!(res, Res::Def(DefKind::Mod, module_def_id)
+                    if module_has_child(cx.tcx, *module_def_id, replacement))
+            }),
+            _ => false,
+        });
+        return imported_by_glob.then_some(edits);
+    };
+
+    let [module_path @ .., _] = import_path.segments else {
+        return None;
+    };
+    let Res::Def(DefKind::Mod, module_def_id) = module_path.last()?.res else {
+        return None;
+    };
+    if import_item.span.from_expansion() || !module_has_child(cx.tcx, module_def_id, replacement) {
+        return None;
+    }
+
+    let already_imported = items.iter().any(|item| {
+        matches!(item.kind, ItemKind::Use(_, UseKind::Single(ident)) if ident.as_str() == replacement)
+    });
+    if !already_imported {
+        // Imports inside lists like `use leptos::task::{spawn_local, tick};` are lowered
+        // to separate items, so the new import goes after the whole `use` item.
+        let import_span = items
+            .iter()
+            .filter(|item| matches!(item.kind, ItemKind::Use(_, UseKind::ListStem)))
+            .map(|item| item.span)
+            .filter(|span| span.contains(import_item.span))
+            .max_by_key(|span| span.hi() - span.lo())
+            .unwrap_or(import_item.span);
+        let indent = clippy_utils::source::snippet_indent(cx, import_span).unwrap_or_default();
+        let module_path = module_path
+            .iter()
+            .map(|segment| {
+                if segment.ident.name == kw::PathRoot {
+                    ""
+                } else {
+                    segment.ident.as_str()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("::");
+        edits.push((
+            import_span.shrink_to_hi(),
+            format!("\n{indent}use {module_path}::{replacement};"),
+        ));
+    }
+
+    Some(edits)
+}
+
+/// Snippet of an expression to be used as the receiver of a method call.
+///
+/// Methods auto-dereference their receivers, so references are removed (`&ev`
+/// becomes `ev`) and parentheses are added when the expression needs them.
+pub fn receiver_snippet(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    applicability: &mut Applicability,
+) -> String {
+    let expr = match expr.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, inner) => inner,
+        _ => expr,
+    };
+    let snippet = snippet_with_applicability(cx, expr.span, "..", applicability);
+    match expr.kind {
+        ExprKind::Path(..)
+        | ExprKind::Field(..)
+        | ExprKind::Call(..)
+        | ExprKind::MethodCall(..)
+        | ExprKind::Index(..)
+        | ExprKind::Block(..) => snippet.into_owned(),
+        _ => format!("({snippet})"),
+    }
+}
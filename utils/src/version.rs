@@ -0,0 +1,112 @@
+//! Version of Leptos that the crate being linted compiles against.
+//!
+//! Some behaviors of Leptos depend on its version, like the availability of
+//! contexts in event listeners, so lints can adjust their diagnostics to it.
+
+use std::fmt;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use rustc_hir::def_id::CrateNum;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::FileName;
+
+/// First Leptos version where contexts are available in event listeners and
+/// `Action`s.
+pub const CONTEXTS_IN_EVENT_LISTENERS: Version = Version::new(0, 8, 9);
+
+/// A semantic version, without pre-release nor build metadata.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    #[must_use]
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    fn parse(version: &str) -> Option<Self> {
+        let version = version.split(['-', '+']).next()?;
+        let mut numbers = version.split('.').map(str::parse);
+        let version = Self::new(
+            numbers.next()?.ok()?,
+            numbers.next()?.ok()?,
+            numbers.next()?.ok()?,
+        );
+        numbers.next().is_none().then_some(version)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Version of the `leptos` crate that the crate being linted depends on.
+///
+/// The crate metadata of `leptos` stores the location of its sources, so the
+/// version is read from the manifest found next to them. `None` when the crate
+/// doesn't depend on `leptos` or the manifest can't be read, like when the paths
+/// of the sources have been remapped.
+pub fn leptos_version(tcx: TyCtxt<'_>) -> Option<Version> {
+    static LEPTOS_VERSION: OnceLock<Option<Version>> = OnceLock::new();
+
+    *LEPTOS_VERSION.get_or_init(|| {
+        tcx.crates(())
+            .iter()
+            .filter(|cnum| tcx.crate_name(**cnum).as_str() == "leptos")
+            .find_map(|cnum| crate_version(tcx, *cnum))
+    })
+}
+
+/// Check if the crate being linted depends on a Leptos version older than the
+/// given one. Unknown versions are considered up to date.
+#[must_use]
+pub fn leptos_is_older_than(tcx: TyCtxt<'_>, version: Version) -> bool {
+    leptos_version(tcx).is_some_and(|leptos_version| leptos_version < version)
+}
+
+/// Version of an external crate, read from the manifest of its package.
+fn crate_version(tcx: TyCtxt<'_>, cnum: CrateNum) -> Option<Version> {
+    let span = tcx.def_span(cnum.as_def_id());
+    let FileName::Real(file_name) = tcx.sess.source_map().span_to_filename(span) else {
+        return None;
+    };
+
+    let (package_dir, manifest) = file_name
+        .local_path()?
+        .ancestors()
+        .skip(1)
+        .find_map(|dir| read_manifest(dir).map(|manifest| (dir, manifest)))?;
+    match manifest.get("package")?.get("version")? {
+        toml::Value::String(version) => Version::parse(version),
+        // Inherited from the workspace, like in Git dependencies
+        toml::Value::Table(table) if table.get("workspace")?.as_bool()? => {
+            package_dir.ancestors().find_map(|dir| {
+                read_manifest(dir)?
+                    .get("workspace")?
+                    .get("package")?
+                    .get("version")?
+                    .as_str()
+                    .and_then(Version::parse)
+            })
+        }
+        _ => None,
+    }
+}
+
+fn read_manifest(dir: &Path) -> Option<toml::Table> {
+    std::fs::read_to_string(dir.join("Cargo.toml"))
+        .ok()?
+        .parse()
+        .ok()
+}